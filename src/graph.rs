@@ -0,0 +1,454 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::{Index, IndexMut};
+
+use pyo3::class::PyMappingProtocol;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyLong, PyTuple};
+use pyo3::Python;
+
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::prelude::*;
+use petgraph::stable_graph::StableUnGraph;
+use petgraph::visit::{
+    GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected,
+    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeCount,
+    NodeIndexable, Visitable,
+};
+
+use super::{edge_not_found, node_not_found, NoEdgeBetweenNodes};
+
+#[pyclass(module = "retworkx")]
+pub struct PyGraph {
+    graph: StableUnGraph<PyObject, PyObject>,
+}
+
+pub type Edges<'a, E> =
+    petgraph::stable_graph::Edges<'a, E, petgraph::Undirected>;
+
+impl GraphBase for PyGraph {
+    type NodeId = NodeIndex;
+    type EdgeId = EdgeIndex;
+}
+
+impl NodeCount for PyGraph {
+    fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+}
+
+impl GraphProp for PyGraph {
+    type EdgeType = petgraph::Undirected;
+    fn is_directed(&self) -> bool {
+        false
+    }
+}
+
+impl petgraph::visit::Visitable for PyGraph {
+    type Map = <StableUnGraph<PyObject, PyObject> as Visitable>::Map;
+    fn visit_map(&self) -> Self::Map {
+        self.graph.visit_map()
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        self.graph.reset_map(map)
+    }
+}
+
+impl petgraph::visit::Data for PyGraph {
+    type NodeWeight = PyObject;
+    type EdgeWeight = PyObject;
+}
+
+impl petgraph::data::DataMap for PyGraph {
+    fn node_weight(&self, id: Self::NodeId) -> Option<&Self::NodeWeight> {
+        self.graph.node_weight(id)
+    }
+    fn edge_weight(&self, id: Self::EdgeId) -> Option<&Self::EdgeWeight> {
+        self.graph.edge_weight(id)
+    }
+}
+
+impl petgraph::data::DataMapMut for PyGraph {
+    fn node_weight_mut(
+        &mut self,
+        id: Self::NodeId,
+    ) -> Option<&mut Self::NodeWeight> {
+        self.graph.node_weight_mut(id)
+    }
+    fn edge_weight_mut(
+        &mut self,
+        id: Self::EdgeId,
+    ) -> Option<&mut Self::EdgeWeight> {
+        self.graph.edge_weight_mut(id)
+    }
+}
+
+impl<'a> IntoNeighbors for &'a PyGraph {
+    type Neighbors = petgraph::stable_graph::Neighbors<'a, PyObject>;
+    fn neighbors(self, n: NodeIndex) -> Self::Neighbors {
+        self.graph.neighbors(n)
+    }
+}
+
+impl<'a> IntoNeighborsDirected for &'a PyGraph {
+    type NeighborsDirected = petgraph::stable_graph::Neighbors<'a, PyObject>;
+    fn neighbors_directed(
+        self,
+        n: NodeIndex,
+        d: petgraph::Direction,
+    ) -> Self::Neighbors {
+        self.graph.neighbors_directed(n, d)
+    }
+}
+
+impl<'a> IntoEdgeReferences for &'a PyGraph {
+    type EdgeRef = petgraph::stable_graph::EdgeReference<'a, PyObject>;
+    type EdgeReferences = petgraph::stable_graph::EdgeReferences<'a, PyObject>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        self.graph.edge_references()
+    }
+}
+
+impl<'a> IntoEdges for &'a PyGraph {
+    type Edges = Edges<'a, PyObject>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        self.graph.edges(a)
+    }
+}
+
+impl<'a> IntoEdgesDirected for &'a PyGraph {
+    type EdgesDirected = Edges<'a, PyObject>;
+    fn edges_directed(
+        self,
+        a: Self::NodeId,
+        dir: petgraph::Direction,
+    ) -> Self::EdgesDirected {
+        self.graph.edges_directed(a, dir)
+    }
+}
+
+impl<'a> IntoNodeIdentifiers for &'a PyGraph {
+    type NodeIdentifiers = petgraph::stable_graph::NodeIndices<'a, PyObject>;
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.graph.node_identifiers()
+    }
+}
+
+impl<'a> IntoNodeReferences for &'a PyGraph {
+    type NodeRef = (NodeIndex, &'a PyObject);
+    type NodeReferences = petgraph::stable_graph::NodeReferences<'a, PyObject>;
+    fn node_references(self) -> Self::NodeReferences {
+        self.graph.node_references()
+    }
+}
+
+impl NodeIndexable for PyGraph {
+    fn node_bound(&self) -> usize {
+        self.graph.node_bound()
+    }
+    fn to_index(&self, ix: NodeIndex) -> usize {
+        self.graph.to_index(ix)
+    }
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        self.graph.from_index(ix)
+    }
+}
+
+impl NodeCompactIndexable for PyGraph {}
+
+impl Index<NodeIndex> for PyGraph {
+    type Output = PyObject;
+    fn index(&self, index: NodeIndex) -> &PyObject {
+        &self.graph[index]
+    }
+}
+
+impl IndexMut<NodeIndex> for PyGraph {
+    fn index_mut(&mut self, index: NodeIndex) -> &mut PyObject {
+        &mut self.graph[index]
+    }
+}
+
+impl Index<EdgeIndex> for PyGraph {
+    type Output = PyObject;
+    fn index(&self, index: EdgeIndex) -> &PyObject {
+        &self.graph[index]
+    }
+}
+
+impl IndexMut<EdgeIndex> for PyGraph {
+    fn index_mut(&mut self, index: EdgeIndex) -> &mut PyObject {
+        &mut self.graph[index]
+    }
+}
+
+impl GetAdjacencyMatrix for PyGraph {
+    type AdjMatrix =
+        <StableUnGraph<PyObject, PyObject> as GetAdjacencyMatrix>::AdjMatrix;
+    fn adjacency_matrix(&self) -> Self::AdjMatrix {
+        self.graph.adjacency_matrix()
+    }
+    fn is_adjacent(
+        &self,
+        matrix: &Self::AdjMatrix,
+        a: NodeIndex,
+        b: NodeIndex,
+    ) -> bool {
+        self.graph.is_adjacent(matrix, a, b)
+    }
+}
+
+#[pymethods]
+impl PyGraph {
+    #[new]
+    fn new(obj: &PyRawObject) {
+        obj.init(PyGraph {
+            graph: StableUnGraph::<PyObject, PyObject>::default(),
+        });
+    }
+
+    fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        let out_dict = PyDict::new(py);
+        let node_dict = PyDict::new(py);
+        let mut out_list: Vec<PyObject> = Vec::new();
+        out_dict.set_item("nodes", node_dict)?;
+        for node_index in self.graph.node_indices() {
+            let node_data = self.graph.node_weight(node_index).unwrap();
+            node_dict.set_item(node_index.index(), node_data)?;
+        }
+        for edge in self.graph.edge_references() {
+            let edge_w = edge.weight();
+            let triplet =
+                (edge.source().index(), edge.target().index(), edge_w)
+                    .to_object(py);
+            out_list.push(triplet);
+        }
+        let py_out_list: PyObject = PyList::new(py, out_list).into();
+        out_dict.set_item("edges", py_out_list)?;
+        Ok(out_dict.into())
+    }
+
+    fn __setstate__(&mut self, state: PyObject) -> PyResult<()> {
+        let mut node_mapping: HashMap<usize, NodeIndex> = HashMap::new();
+        self.graph = StableUnGraph::<PyObject, PyObject>::default();
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let dict_state = state.cast_as::<PyDict>(py)?;
+
+        let nodes_dict = dict_state
+            .get_item("nodes")
+            .unwrap()
+            .downcast_ref::<PyDict>()?;
+        let edges_list = dict_state
+            .get_item("edges")
+            .unwrap()
+            .downcast_ref::<PyList>()?;
+        for raw_index in nodes_dict.keys().iter() {
+            let tmp_index = raw_index.downcast_ref::<PyLong>()?;
+            let index: usize = tmp_index.extract()?;
+            let raw_data = nodes_dict.get_item(index).unwrap();
+            let node_index = self.graph.add_node(raw_data.into());
+            node_mapping.insert(index, node_index);
+        }
+        for raw_edge in edges_list.iter() {
+            let edge = raw_edge.downcast_ref::<PyTuple>()?;
+            let raw_p_index = edge.get_item(0).downcast_ref::<PyLong>()?;
+            let tmp_p_index: usize = raw_p_index.extract()?;
+            let raw_c_index = edge.get_item(1).downcast_ref::<PyLong>()?;
+            let tmp_c_index: usize = raw_c_index.extract()?;
+            let edge_data = edge.get_item(2);
+
+            let p_index = node_mapping.get(&tmp_p_index).unwrap();
+            let c_index = node_mapping.get(&tmp_c_index).unwrap();
+            self.graph.add_edge(*p_index, *c_index, edge_data.into());
+        }
+        Ok(())
+    }
+
+    pub fn edges(&self, py: Python) -> PyObject {
+        let raw_edges = self.graph.edge_indices();
+        let mut out: Vec<&PyObject> = Vec::new();
+        for edge in raw_edges {
+            out.push(self.graph.edge_weight(edge).unwrap());
+        }
+        PyList::new(py, out).into()
+    }
+
+    pub fn nodes(&self, py: Python) -> PyObject {
+        let raw_nodes = self.graph.node_indices();
+        let mut out: Vec<&PyObject> = Vec::new();
+        for node in raw_nodes {
+            out.push(self.graph.node_weight(node).unwrap());
+        }
+        PyList::new(py, out).into()
+    }
+
+    pub fn has_edge(&self, node_a: usize, node_b: usize) -> PyResult<bool> {
+        let index_a = self.node_index(node_a)?;
+        let index_b = self.node_index(node_b)?;
+        Ok(self.graph.find_edge(index_a, index_b).is_some())
+    }
+
+    pub fn get_edge_data(
+        &self,
+        node_a: usize,
+        node_b: usize,
+    ) -> PyResult<&PyObject> {
+        let index_a = self.node_index(node_a)?;
+        let index_b = self.node_index(node_b)?;
+        let edge_index = match self.graph.find_edge(index_a, index_b) {
+            Some(edge_index) => edge_index,
+            None => {
+                return Err(NoEdgeBetweenNodes::py_err(
+                    "No edge found between nodes",
+                ))
+            }
+        };
+
+        let data = self.graph.edge_weight(edge_index).unwrap();
+        Ok(data)
+    }
+
+    pub fn get_node_data(&self, node: usize) -> PyResult<&PyObject> {
+        let index = self.node_index(node)?;
+        Ok(&self.graph[index])
+    }
+
+    pub fn get_all_edge_data(
+        &self,
+        py: Python,
+        node_a: usize,
+        node_b: usize,
+    ) -> PyResult<PyObject> {
+        let index_a = self.node_index(node_a)?;
+        let index_b = self.node_index(node_b)?;
+        let raw_edges = self.graph.edges(index_a);
+        let mut out: Vec<&PyObject> = Vec::new();
+        for edge in raw_edges {
+            if edge.target() == index_b {
+                out.push(edge.weight());
+            }
+        }
+        if out.is_empty() {
+            Err(NoEdgeBetweenNodes::py_err("No edge found between nodes"))
+        } else {
+            Ok(PyList::new(py, out).into())
+        }
+    }
+
+    pub fn remove_node(&mut self, node: usize) -> PyResult<()> {
+        let index = self.node_index(node)?;
+        self.graph.remove_node(index);
+
+        Ok(())
+    }
+
+    pub fn add_edge(
+        &mut self,
+        node_a: usize,
+        node_b: usize,
+        edge: PyObject,
+    ) -> PyResult<usize> {
+        let index_a = self.node_index(node_a)?;
+        let index_b = self.node_index(node_b)?;
+        let edge = self.graph.add_edge(index_a, index_b, edge);
+        Ok(edge.index())
+    }
+
+    pub fn remove_edge(
+        &mut self,
+        node_a: usize,
+        node_b: usize,
+    ) -> PyResult<()> {
+        let index_a = self.node_index(node_a)?;
+        let index_b = self.node_index(node_b)?;
+        let edge_index = match self.graph.find_edge(index_a, index_b) {
+            Some(edge_index) => edge_index,
+            None => {
+                return Err(NoEdgeBetweenNodes::py_err(
+                    "No edge found between nodes",
+                ))
+            }
+        };
+        self.graph.remove_edge(edge_index);
+        Ok(())
+    }
+
+    pub fn remove_edge_from_index(&mut self, edge: usize) -> PyResult<()> {
+        let edge_index = self.edge_index(edge)?;
+        self.graph.remove_edge(edge_index);
+        Ok(())
+    }
+
+    pub fn add_node(&mut self, obj: PyObject) -> PyResult<usize> {
+        let index = self.graph.add_node(obj);
+        Ok(index.index())
+    }
+
+    pub fn adj(&mut self, py: Python, node: usize) -> PyResult<PyObject> {
+        let index = self.node_index(node)?;
+        let out_dict = PyDict::new(py);
+        for edge in self.graph.edges(index) {
+            out_dict.set_item(edge.target().index(), edge.weight())?;
+        }
+        Ok(out_dict.into())
+    }
+
+    pub fn neighbors(&self, py: Python, node: usize) -> PyResult<PyObject> {
+        let index = self.node_index(node)?;
+        let mut out: Vec<usize> = Vec::new();
+        let mut used_indexes: HashSet<NodeIndex> = HashSet::new();
+        for neighbor in self.graph.neighbors(index) {
+            if !used_indexes.contains(&neighbor) {
+                out.push(neighbor.index());
+                used_indexes.insert(neighbor);
+            }
+        }
+        Ok(PyList::new(py, out).into())
+    }
+
+    pub fn degree(&self, node: usize) -> PyResult<usize> {
+        let index = self.node_index(node)?;
+        let neighbors = self.graph.edges(index);
+        Ok(neighbors.count())
+    }
+}
+
+impl PyGraph {
+    fn node_index(&self, node: usize) -> PyResult<NodeIndex> {
+        let index = NodeIndex::new(node);
+        if index.index() != node || !self.graph.contains_node(index) {
+            let gil = Python::acquire_gil();
+            return Err(node_not_found(gil.python(), node));
+        }
+        Ok(index)
+    }
+
+    fn edge_index(&self, edge: usize) -> PyResult<EdgeIndex> {
+        let index = EdgeIndex::new(edge);
+        if index.index() != edge || self.graph.edge_weight(index).is_none() {
+            let gil = Python::acquire_gil();
+            return Err(edge_not_found(gil.python(), edge));
+        }
+        Ok(index)
+    }
+}
+
+#[pyproto]
+impl PyMappingProtocol for PyGraph {
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self.graph.node_count())
+    }
+}
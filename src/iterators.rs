@@ -0,0 +1,537 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+// Read-only sequence types returned by methods and functions that return
+// node or edge indices or edges instead of a list. Indices are kept as Rust
+// integers and only converted to Python objects when an element is
+// accessed. The edge data in a WeightedEdgeList is already made of Python
+// objects so it is held by reference. Methods returning only node or edge
+// data, like PyDAG.nodes() and PyDAG.edges(), keep returning a list as there
+// is nothing to convert lazily for them.
+
+use std::os::raw::c_long;
+
+use pyo3::class::basic::CompareOp;
+use pyo3::class::{
+    PyIterProtocol, PyMappingProtocol, PyObjectProtocol, PySequenceProtocol,
+};
+use pyo3::exceptions::{IndexError, ValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyAny, PyList, PySequence, PySlice};
+use pyo3::PyRefMut;
+use pyo3::Python;
+
+/// Convert a (possibly negative) python index into an index into a
+/// sequence of length ``len``.
+fn sequence_index(idx: isize, len: usize) -> PyResult<usize> {
+    let idx = if idx < 0 { idx + len as isize } else { idx };
+    if idx < 0 || idx >= len as isize {
+        return Err(IndexError::py_err("Index out of range"));
+    }
+    Ok(idx as usize)
+}
+
+/// Clamp a (possibly negative) python index into the range ``0..=len`` like
+/// the start and stop arguments of ``list.index``.
+fn sequence_bound(idx: isize, len: usize) -> usize {
+    if idx < 0 {
+        (idx + len as isize).max(0) as usize
+    } else {
+        (idx as usize).min(len)
+    }
+}
+
+/// The elements selected by the key passed to ``__getitem__``
+enum SequenceKey {
+    Index(usize),
+    Slice(Vec<usize>),
+}
+
+fn sequence_key(key: &PyAny, len: usize) -> PyResult<SequenceKey> {
+    if let Ok(slice) = key.downcast_ref::<PySlice>() {
+        let indices = slice.indices(len as c_long)?;
+        let positions = (0..indices.slicelength)
+            .map(|i| (indices.start + i * indices.step) as usize)
+            .collect();
+        return Ok(SequenceKey::Slice(positions));
+    }
+    let idx: isize = key.extract()?;
+    Ok(SequenceKey::Index(sequence_index(idx, len)?))
+}
+
+fn item_eq(py: Python, item: &PyObject, other: &PyAny) -> PyResult<bool> {
+    item.as_ref(py)
+        .rich_compare(other, CompareOp::Eq)?
+        .is_true(py)
+}
+
+fn sequence_contains<T: ToPyObject>(
+    py: Python,
+    items: &[T],
+    value: &PyAny,
+) -> PyResult<bool> {
+    for item in items {
+        if item_eq(py, &item.to_object(py), value)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn sequence_count<T: ToPyObject>(
+    py: Python,
+    items: &[T],
+    value: &PyAny,
+) -> PyResult<usize> {
+    let mut count = 0;
+    for item in items {
+        if item_eq(py, &item.to_object(py), value)? {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+fn sequence_position<T: ToPyObject>(
+    py: Python,
+    items: &[T],
+    value: &PyAny,
+    start: isize,
+    stop: Option<isize>,
+) -> PyResult<usize> {
+    let start = sequence_bound(start, items.len());
+    let stop = match stop {
+        Some(stop) => sequence_bound(stop, items.len()),
+        None => items.len(),
+    };
+    for (i, item) in items.iter().enumerate().take(stop).skip(start) {
+        if item_eq(py, &item.to_object(py), value)? {
+            return Ok(i);
+        }
+    }
+    Err(ValueError::py_err("Value is not in the sequence"))
+}
+
+/// Check if ``other`` can be compared to a sequence type. The types in this
+/// module only implement indexing through the mapping protocol so they
+/// aren't a ``PySequence`` themselves.
+fn is_sequence(other: &PyAny) -> bool {
+    other.downcast_ref::<PySequence>().is_ok()
+        || other.downcast_ref::<NodeIndices>().is_ok()
+        || other.downcast_ref::<EdgeIndices>().is_ok()
+        || other.downcast_ref::<EdgeList>().is_ok()
+        || other.downcast_ref::<WeightedEdgeList>().is_ok()
+}
+
+/// Compare ``items`` to another sequence lexicographically the same way a
+/// list is compared. Any other object isn't supported.
+fn richcmp<T: ToPyObject>(
+    py: Python,
+    items: &[T],
+    other: &PyAny,
+    op: CompareOp,
+) -> PyResult<PyObject> {
+    if !is_sequence(other) {
+        return Ok(py.NotImplemented());
+    }
+    let len = items.len();
+    let other_len = other.len()?;
+    if len != other_len {
+        match op {
+            CompareOp::Eq => return Ok(false.to_object(py)),
+            CompareOp::Ne => return Ok(true.to_object(py)),
+            _ => (),
+        }
+    }
+    for (i, item) in items.iter().take(other_len).enumerate() {
+        let item = item.to_object(py);
+        let other_item = other.get_item(i)?;
+        if !item_eq(py, &item, other_item)? {
+            return match op {
+                CompareOp::Eq => Ok(false.to_object(py)),
+                CompareOp::Ne => Ok(true.to_object(py)),
+                op => item.as_ref(py).rich_compare(other_item, op),
+            };
+        }
+    }
+    let res = match op {
+        CompareOp::Lt => len < other_len,
+        CompareOp::Le => len <= other_len,
+        CompareOp::Eq => len == other_len,
+        CompareOp::Ne => len != other_len,
+        CompareOp::Gt => len > other_len,
+        CompareOp::Ge => len >= other_len,
+    };
+    Ok(res.to_object(py))
+}
+
+/// A sequence of node indices
+#[pyclass(module = "retworkx")]
+pub struct NodeIndices {
+    pub nodes: Vec<usize>,
+}
+
+#[pymethods]
+impl NodeIndices {
+    #[new]
+    fn new(obj: &PyRawObject) {
+        obj.init(NodeIndices { nodes: Vec::new() });
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        PyList::new(py, &self.nodes).into()
+    }
+
+    fn __setstate__(&mut self, state: Vec<usize>) {
+        self.nodes = state;
+    }
+
+    #[args(start = "0", stop = "None")]
+    fn index(
+        &self,
+        py: Python,
+        value: &PyAny,
+        start: isize,
+        stop: Option<isize>,
+    ) -> PyResult<usize> {
+        sequence_position(py, &self.nodes, value, start, stop)
+    }
+
+    fn count(&self, py: Python, value: &PyAny) -> PyResult<usize> {
+        sequence_count(py, &self.nodes, value)
+    }
+}
+
+#[pyproto]
+impl PySequenceProtocol for NodeIndices {
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self.nodes.len())
+    }
+
+    fn __contains__(&self, value: &'p PyAny) -> PyResult<bool> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        sequence_contains(py, &self.nodes, value)
+    }
+}
+
+#[pyproto]
+impl PyMappingProtocol for NodeIndices {
+    fn __getitem__(&self, key: &'p PyAny) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match sequence_key(key, self.nodes.len())? {
+            SequenceKey::Index(idx) => Ok(self.nodes[idx].to_object(py)),
+            SequenceKey::Slice(positions) => {
+                let nodes = positions.iter().map(|&i| self.nodes[i]).collect();
+                Ok(Py::new(py, NodeIndices { nodes })?.to_object(py))
+            }
+        }
+    }
+}
+
+#[pyproto]
+impl PyIterProtocol for NodeIndices {
+    fn __iter__(slf: PyRefMut<Self>) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let iter = PyList::new(py, &slf.nodes).call_method0("__iter__")?;
+        Ok(iter.to_object(py))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for NodeIndices {
+    fn __richcmp__(
+        &self,
+        other: &'p PyAny,
+        op: CompareOp,
+    ) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        richcmp(py, &self.nodes, other, op)
+    }
+}
+
+/// A sequence of edge indices
+#[pyclass(module = "retworkx")]
+pub struct EdgeIndices {
+    pub edges: Vec<usize>,
+}
+
+#[pymethods]
+impl EdgeIndices {
+    #[new]
+    fn new(obj: &PyRawObject) {
+        obj.init(EdgeIndices { edges: Vec::new() });
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        PyList::new(py, &self.edges).into()
+    }
+
+    fn __setstate__(&mut self, state: Vec<usize>) {
+        self.edges = state;
+    }
+
+    #[args(start = "0", stop = "None")]
+    fn index(
+        &self,
+        py: Python,
+        value: &PyAny,
+        start: isize,
+        stop: Option<isize>,
+    ) -> PyResult<usize> {
+        sequence_position(py, &self.edges, value, start, stop)
+    }
+
+    fn count(&self, py: Python, value: &PyAny) -> PyResult<usize> {
+        sequence_count(py, &self.edges, value)
+    }
+}
+
+#[pyproto]
+impl PySequenceProtocol for EdgeIndices {
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self.edges.len())
+    }
+
+    fn __contains__(&self, value: &'p PyAny) -> PyResult<bool> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        sequence_contains(py, &self.edges, value)
+    }
+}
+
+#[pyproto]
+impl PyMappingProtocol for EdgeIndices {
+    fn __getitem__(&self, key: &'p PyAny) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match sequence_key(key, self.edges.len())? {
+            SequenceKey::Index(idx) => Ok(self.edges[idx].to_object(py)),
+            SequenceKey::Slice(positions) => {
+                let edges = positions.iter().map(|&i| self.edges[i]).collect();
+                Ok(Py::new(py, EdgeIndices { edges })?.to_object(py))
+            }
+        }
+    }
+}
+
+#[pyproto]
+impl PyIterProtocol for EdgeIndices {
+    fn __iter__(slf: PyRefMut<Self>) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let iter = PyList::new(py, &slf.edges).call_method0("__iter__")?;
+        Ok(iter.to_object(py))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for EdgeIndices {
+    fn __richcmp__(
+        &self,
+        other: &'p PyAny,
+        op: CompareOp,
+    ) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        richcmp(py, &self.edges, other, op)
+    }
+}
+
+/// A sequence of edges as ``(source, target)`` tuples of node indices
+#[pyclass(module = "retworkx")]
+pub struct EdgeList {
+    pub edges: Vec<(usize, usize)>,
+}
+
+#[pymethods]
+impl EdgeList {
+    #[new]
+    fn new(obj: &PyRawObject) {
+        obj.init(EdgeList { edges: Vec::new() });
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        PyList::new(py, &self.edges).into()
+    }
+
+    fn __setstate__(&mut self, state: Vec<(usize, usize)>) {
+        self.edges = state;
+    }
+
+    #[args(start = "0", stop = "None")]
+    fn index(
+        &self,
+        py: Python,
+        value: &PyAny,
+        start: isize,
+        stop: Option<isize>,
+    ) -> PyResult<usize> {
+        sequence_position(py, &self.edges, value, start, stop)
+    }
+
+    fn count(&self, py: Python, value: &PyAny) -> PyResult<usize> {
+        sequence_count(py, &self.edges, value)
+    }
+}
+
+#[pyproto]
+impl PySequenceProtocol for EdgeList {
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self.edges.len())
+    }
+
+    fn __contains__(&self, value: &'p PyAny) -> PyResult<bool> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        sequence_contains(py, &self.edges, value)
+    }
+}
+
+#[pyproto]
+impl PyMappingProtocol for EdgeList {
+    fn __getitem__(&self, key: &'p PyAny) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match sequence_key(key, self.edges.len())? {
+            SequenceKey::Index(idx) => Ok(self.edges[idx].to_object(py)),
+            SequenceKey::Slice(positions) => {
+                let edges = positions.iter().map(|&i| self.edges[i]).collect();
+                Ok(Py::new(py, EdgeList { edges })?.to_object(py))
+            }
+        }
+    }
+}
+
+#[pyproto]
+impl PyIterProtocol for EdgeList {
+    fn __iter__(slf: PyRefMut<Self>) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let iter = PyList::new(py, &slf.edges).call_method0("__iter__")?;
+        Ok(iter.to_object(py))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for EdgeList {
+    fn __richcmp__(
+        &self,
+        other: &'p PyAny,
+        op: CompareOp,
+    ) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        richcmp(py, &self.edges, other, op)
+    }
+}
+
+/// A sequence of edges as ``(source, target, weight)`` tuples
+#[pyclass(module = "retworkx")]
+pub struct WeightedEdgeList {
+    pub edges: Vec<(usize, usize, PyObject)>,
+}
+
+#[pymethods]
+impl WeightedEdgeList {
+    #[new]
+    fn new(obj: &PyRawObject) {
+        obj.init(WeightedEdgeList { edges: Vec::new() });
+    }
+
+    fn __getstate__(&self, py: Python) -> PyObject {
+        let out: Vec<PyObject> =
+            self.edges.iter().map(|edge| edge.to_object(py)).collect();
+        PyList::new(py, out).into()
+    }
+
+    fn __setstate__(&mut self, state: Vec<(usize, usize, PyObject)>) {
+        self.edges = state;
+    }
+
+    #[args(start = "0", stop = "None")]
+    fn index(
+        &self,
+        py: Python,
+        value: &PyAny,
+        start: isize,
+        stop: Option<isize>,
+    ) -> PyResult<usize> {
+        sequence_position(py, &self.edges, value, start, stop)
+    }
+
+    fn count(&self, py: Python, value: &PyAny) -> PyResult<usize> {
+        sequence_count(py, &self.edges, value)
+    }
+}
+
+#[pyproto]
+impl PySequenceProtocol for WeightedEdgeList {
+    fn __len__(&self) -> PyResult<usize> {
+        Ok(self.edges.len())
+    }
+
+    fn __contains__(&self, value: &'p PyAny) -> PyResult<bool> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        sequence_contains(py, &self.edges, value)
+    }
+}
+
+#[pyproto]
+impl PyMappingProtocol for WeightedEdgeList {
+    fn __getitem__(&self, key: &'p PyAny) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        match sequence_key(key, self.edges.len())? {
+            SequenceKey::Index(idx) => Ok(self.edges[idx].to_object(py)),
+            SequenceKey::Slice(positions) => {
+                let edges = positions
+                    .iter()
+                    .map(|&i| {
+                        let (source, target, ref weight) = self.edges[i];
+                        (source, target, weight.clone_ref(py))
+                    })
+                    .collect();
+                Ok(Py::new(py, WeightedEdgeList { edges })?.to_object(py))
+            }
+        }
+    }
+}
+
+#[pyproto]
+impl PyIterProtocol for WeightedEdgeList {
+    fn __iter__(slf: PyRefMut<Self>) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let out: Vec<PyObject> =
+            slf.edges.iter().map(|edge| edge.to_object(py)).collect();
+        let iter = PyList::new(py, out).call_method0("__iter__")?;
+        Ok(iter.to_object(py))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for WeightedEdgeList {
+    fn __richcmp__(
+        &self,
+        other: &'p PyAny,
+        op: CompareOp,
+    ) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        richcmp(py, &self.edges, other, op)
+    }
+}
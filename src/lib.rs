@@ -15,28 +15,39 @@ extern crate petgraph;
 extern crate pyo3;
 
 mod dag_isomorphism;
+mod graph;
+mod iterators;
+mod topo_order;
 
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap, HashSet};
-use std::ops::{Index, IndexMut};
+use std::cmp::{Ordering, Reverse};
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::ops::{Add, Index, IndexMut, Sub};
+use std::vec::IntoIter;
 
-use pyo3::class::PyMappingProtocol;
+use fixedbitset::FixedBitSet;
+
+use pyo3::class::{PyIterProtocol, PyMappingProtocol, PySequenceProtocol};
 use pyo3::create_exception;
 use pyo3::exceptions::{Exception, IndexError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyLong, PyTuple};
+use pyo3::types::{PyAny, PyDict, PyList, PyLong, PySet, PyTuple};
 use pyo3::wrap_pyfunction;
+use pyo3::PyRefMut;
 use pyo3::Python;
 
+use iterators::{EdgeIndices, EdgeList, NodeIndices, WeightedEdgeList};
+use topo_order::TopoOrder;
+
 use petgraph::algo;
 use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::*;
 use petgraph::stable_graph::StableDiGraph;
 use petgraph::visit::{
-    Bfs, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences,
-    IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected,
-    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeCount,
-    NodeIndexable, Visitable,
+    Dfs, DfsPostOrder, GetAdjacencyMatrix, GraphBase, GraphProp,
+    IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors,
+    IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences,
+    NodeCompactIndexable, NodeCount, NodeIndexable, Visitable,
 };
 
 #[pyclass(module = "retworkx")]
@@ -46,6 +57,9 @@ pub struct PyDAG {
         NodeIndex,
         <StableDiGraph<PyObject, PyObject> as Visitable>::Map,
     >,
+    topo_order: TopoOrder,
+    check_cycle: bool,
+    attrs: PyObject,
 }
 
 pub type Edges<'a, E> =
@@ -226,10 +240,16 @@ impl GetAdjacencyMatrix for PyDAG {
 #[pymethods]
 impl PyDAG {
     #[new]
-    fn new(obj: &PyRawObject) {
+    #[args(check_cycle = "true", attrs = "None")]
+    fn new(obj: &PyRawObject, check_cycle: bool, attrs: Option<PyObject>) {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
         obj.init(PyDAG {
             graph: StableDiGraph::<PyObject, PyObject>::new(),
             cycle_state: algo::DfsSpace::default(),
+            topo_order: TopoOrder::new(),
+            check_cycle,
+            attrs: attrs.unwrap_or_else(|| py.None()),
         });
     }
 
@@ -253,6 +273,8 @@ impl PyDAG {
         }
         let py_out_list: PyObject = PyList::new(py, out_list).into();
         out_dict.set_item("edges", py_out_list)?;
+        out_dict.set_item("check_cycle", self.check_cycle)?;
+        out_dict.set_item("attrs", &self.attrs)?;
         Ok(out_dict.into())
     }
 
@@ -262,6 +284,14 @@ impl PyDAG {
         let gil = Python::acquire_gil();
         let py = gil.python();
         let dict_state = state.cast_as::<PyDict>(py)?;
+        self.check_cycle = match dict_state.get_item("check_cycle") {
+            Some(check_cycle) => check_cycle.extract()?,
+            None => true,
+        };
+        self.attrs = match dict_state.get_item("attrs") {
+            Some(attrs) => attrs.into(),
+            None => py.None(),
+        };
 
         let nodes_dict = dict_state
             .get_item("nodes")
@@ -290,9 +320,103 @@ impl PyDAG {
             let c_index = node_mapping.get(&tmp_c_index).unwrap();
             self.graph.add_edge(*p_index, *c_index, edge_data.into());
         }
+        self.rebuild_topo_order();
+        if self.check_cycle && !self.topo_order.is_valid() {
+            // Leave the object in a consistent state for the caller
+            self.check_cycle = false;
+            return Err(DAGHasCycle::py_err(
+                "Restored PyDAG object has a cycle while check_cycle is set",
+            ));
+        }
+        Ok(())
+    }
+
+    #[getter]
+    fn get_check_cycle(&self) -> PyResult<bool> {
+        Ok(self.check_cycle)
+    }
+
+    #[setter]
+    fn set_check_cycle(&mut self, value: bool) -> PyResult<()> {
+        if value && !self.check_cycle {
+            self.rebuild_topo_order();
+            if !self.topo_order.is_valid() {
+                return Err(DAGWouldCycle::py_err("PyDAG object has a cycle"));
+            }
+        }
+        self.check_cycle = value;
+        Ok(())
+    }
+
+    #[getter]
+    fn get_attrs(&self) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        Ok(self.attrs.clone_ref(py))
+    }
+
+    #[setter]
+    fn set_attrs(&mut self, attrs: PyObject) -> PyResult<()> {
+        self.attrs = attrs;
         Ok(())
     }
 
+    pub fn copy(&self, py: Python) -> PyDAG {
+        let graph = self
+            .graph
+            .map(|_, node| node.clone_ref(py), |_, edge| edge.clone_ref(py));
+        self.new_like(py, graph)
+    }
+
+    pub fn subgraph(
+        &self,
+        py: Python,
+        nodes: Vec<usize>,
+    ) -> PyResult<(PyDAG, PyObject)> {
+        let mut node_map: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut node_order: Vec<(NodeIndex, NodeIndex)> = Vec::new();
+        let mut graph = StableDiGraph::<PyObject, PyObject>::new();
+        for node in nodes {
+            let index = self.node_index(node)?;
+            if let Entry::Vacant(entry) = node_map.entry(index) {
+                let new_index = graph.add_node(self.graph[index].clone_ref(py));
+                entry.insert(new_index);
+                node_order.push((index, new_index));
+            }
+        }
+        let dir = petgraph::Direction::Outgoing;
+        for (index, new_index) in node_order.iter() {
+            for edge in self.graph.edges_directed(*index, dir) {
+                if let Some(new_target) = node_map.get(&edge.target()) {
+                    graph.add_edge(
+                        *new_index,
+                        *new_target,
+                        edge.weight().clone_ref(py),
+                    );
+                }
+            }
+        }
+        let out_dict = PyDict::new(py);
+        for (index, new_index) in node_order.iter() {
+            out_dict.set_item(index.index(), new_index.index())?;
+        }
+        Ok((self.new_like(py, graph), out_dict.into()))
+    }
+
+    #[args(inplace = "false")]
+    pub fn reverse(&mut self, py: Python, inplace: bool) -> PyObject {
+        if inplace {
+            reverse_edges(&mut self.graph);
+            self.rebuild_topo_order();
+            py.None()
+        } else {
+            let mut new_dag = self.copy(py);
+            reverse_edges(&mut new_dag.graph);
+            new_dag.rebuild_topo_order();
+            new_dag.into_py(py)
+        }
+    }
+
     pub fn edges(&self, py: Python) -> PyObject {
         let raw_edges = self.graph.edge_indices();
         let mut out: Vec<&PyObject> = Vec::new();
@@ -302,6 +426,34 @@ impl PyDAG {
         PyList::new(py, out).into()
     }
 
+    pub fn edge_indices(&self) -> EdgeIndices {
+        let mut out: Vec<usize> = Vec::new();
+        for edge in self.graph.edge_indices() {
+            out.push(edge.index());
+        }
+        EdgeIndices { edges: out }
+    }
+
+    pub fn edge_list(&self) -> EdgeList {
+        let mut out: Vec<(usize, usize)> = Vec::new();
+        for edge in self.graph.edge_references() {
+            out.push((edge.source().index(), edge.target().index()));
+        }
+        EdgeList { edges: out }
+    }
+
+    pub fn weighted_edge_list(&self, py: Python) -> WeightedEdgeList {
+        let mut out: Vec<(usize, usize, PyObject)> = Vec::new();
+        for edge in self.graph.edge_references() {
+            out.push((
+                edge.source().index(),
+                edge.target().index(),
+                edge.weight().clone_ref(py),
+            ));
+        }
+        WeightedEdgeList { edges: out }
+    }
+
     pub fn nodes(&self, py: Python) -> PyObject {
         let raw_nodes = self.graph.node_indices();
         let mut out: Vec<&PyObject> = Vec::new();
@@ -311,14 +463,14 @@ impl PyDAG {
         PyList::new(py, out).into()
     }
 
-    pub fn has_edge(&self, node_a: usize, node_b: usize) -> bool {
-        let index_a = NodeIndex::new(node_a);
-        let index_b = NodeIndex::new(node_b);
-        self.graph.find_edge(index_a, index_b).is_some()
+    pub fn has_edge(&self, node_a: usize, node_b: usize) -> PyResult<bool> {
+        let index_a = self.node_index(node_a)?;
+        let index_b = self.node_index(node_b)?;
+        Ok(self.graph.find_edge(index_a, index_b).is_some())
     }
 
     pub fn successors(&self, py: Python, node: usize) -> PyResult<PyObject> {
-        let index = NodeIndex::new(node);
+        let index = self.node_index(node)?;
         let children = self
             .graph
             .neighbors_directed(index, petgraph::Direction::Outgoing);
@@ -334,7 +486,7 @@ impl PyDAG {
     }
 
     pub fn predecessors(&self, py: Python, node: usize) -> PyResult<PyObject> {
-        let index = NodeIndex::new(node);
+        let index = self.node_index(node)?;
         let parents = self
             .graph
             .neighbors_directed(index, petgraph::Direction::Incoming);
@@ -354,8 +506,8 @@ impl PyDAG {
         node_a: usize,
         node_b: usize,
     ) -> PyResult<&PyObject> {
-        let index_a = NodeIndex::new(node_a);
-        let index_b = NodeIndex::new(node_b);
+        let index_a = self.node_index(node_a)?;
+        let index_b = self.node_index(node_b)?;
         let edge_index = match self.graph.find_edge(index_a, index_b) {
             Some(edge_index) => edge_index,
             None => {
@@ -369,13 +521,33 @@ impl PyDAG {
         Ok(data)
     }
 
+    pub fn get_edge_data_by_index(&self, edge: usize) -> PyResult<&PyObject> {
+        let edge_index = self.edge_index(edge)?;
+        Ok(&self.graph[edge_index])
+    }
+
+    pub fn get_edge_endpoints_by_index(
+        &self,
+        edge: usize,
+    ) -> PyResult<(usize, usize)> {
+        let edge_index = self.edge_index(edge)?;
+        let endpoints = self.graph.edge_endpoints(edge_index).unwrap();
+        Ok((endpoints.0.index(), endpoints.1.index()))
+    }
+
+    pub fn update_edge_by_index(
+        &mut self,
+        edge: usize,
+        obj: PyObject,
+    ) -> PyResult<()> {
+        let edge_index = self.edge_index(edge)?;
+        self.graph[edge_index] = obj;
+        Ok(())
+    }
+
     pub fn get_node_data(&self, node: usize) -> PyResult<&PyObject> {
-        let index = NodeIndex::new(node);
-        let node = match self.graph.node_weight(index) {
-            Some(node) => node,
-            None => return Err(IndexError::py_err("No node found for index")),
-        };
-        Ok(node)
+        let index = self.node_index(node)?;
+        Ok(&self.graph[index])
     }
 
     pub fn get_all_edge_data(
@@ -384,8 +556,8 @@ impl PyDAG {
         node_a: usize,
         node_b: usize,
     ) -> PyResult<PyObject> {
-        let index_a = NodeIndex::new(node_a);
-        let index_b = NodeIndex::new(node_b);
+        let index_a = self.node_index(node_a)?;
+        let index_b = self.node_index(node_b)?;
         let raw_edges = self
             .graph
             .edges_directed(index_a, petgraph::Direction::Outgoing);
@@ -403,8 +575,9 @@ impl PyDAG {
     }
 
     pub fn remove_node(&mut self, node: usize) -> PyResult<()> {
-        let index = NodeIndex::new(node);
+        let index = self.node_index(node)?;
         self.graph.remove_node(index);
+        self.topo_order.remove_node(index);
 
         Ok(())
     }
@@ -415,24 +588,15 @@ impl PyDAG {
         child: usize,
         edge: PyObject,
     ) -> PyResult<usize> {
-        let p_index = NodeIndex::new(parent);
-        let c_index = NodeIndex::new(child);
-        let should_check_for_cycle =
-            must_check_for_cycle(self, p_index, c_index);
-        let state = Some(&mut self.cycle_state);
-        if should_check_for_cycle
-            && algo::has_path_connecting(&self.graph, c_index, p_index, state)
-        {
-            Err(DAGWouldCycle::py_err("Adding an edge would cycle"))
-        } else {
-            let edge = self.graph.add_edge(p_index, c_index, edge);
-            Ok(edge.index())
-        }
+        let p_index = self.node_index(parent)?;
+        let c_index = self.node_index(child)?;
+        let edge = self.add_edge_checked(p_index, c_index, edge)?;
+        Ok(edge.index())
     }
 
     pub fn remove_edge(&mut self, parent: usize, child: usize) -> PyResult<()> {
-        let p_index = NodeIndex::new(parent);
-        let c_index = NodeIndex::new(child);
+        let p_index = self.node_index(parent)?;
+        let c_index = self.node_index(child)?;
         let edge_index = match self.graph.find_edge(p_index, c_index) {
             Some(edge_index) => edge_index,
             None => {
@@ -446,13 +610,14 @@ impl PyDAG {
     }
 
     pub fn remove_edge_from_index(&mut self, edge: usize) -> PyResult<()> {
-        let edge_index = EdgeIndex::new(edge);
+        let edge_index = self.edge_index(edge)?;
         self.graph.remove_edge(edge_index);
         Ok(())
     }
 
     pub fn add_node(&mut self, obj: PyObject) -> PyResult<usize> {
         let index = self.graph.add_node(obj);
+        self.topo_order.add_node(index);
         Ok(index.index())
     }
 
@@ -462,8 +627,9 @@ impl PyDAG {
         obj: PyObject,
         edge: PyObject,
     ) -> PyResult<usize> {
-        let index = NodeIndex::new(parent);
+        let index = self.node_index(parent)?;
         let child_node = self.graph.add_node(obj);
+        self.topo_order.add_node(child_node);
         self.graph.add_edge(index, child_node, edge);
         Ok(child_node.index())
     }
@@ -474,14 +640,156 @@ impl PyDAG {
         obj: PyObject,
         edge: PyObject,
     ) -> PyResult<usize> {
-        let index = NodeIndex::new(child);
+        let index = self.node_index(child)?;
         let parent_node = self.graph.add_node(obj);
+        self.topo_order.add_node_front(parent_node);
         self.graph.add_edge(parent_node, index, edge);
         Ok(parent_node.index())
     }
 
+    #[args(weight_combo_fn = "None")]
+    pub fn contract_nodes(
+        &mut self,
+        py: Python,
+        nodes: Vec<usize>,
+        obj: PyObject,
+        weight_combo_fn: Option<PyObject>,
+    ) -> PyResult<usize> {
+        let mut indices: Vec<NodeIndex> = Vec::with_capacity(nodes.len());
+        let mut node_set: HashSet<NodeIndex> = HashSet::new();
+        for node in nodes {
+            let index = self.node_index(node)?;
+            if node_set.insert(index) {
+                indices.push(index);
+            }
+        }
+        if self.check_cycle {
+            // The contraction creates a cycle if there is a node outside of
+            // the contracted set that is both reachable from the set and can
+            // reach the set.
+            let dir = petgraph::Direction::Outgoing;
+            let descendants = reachable_nodes(&self.graph, &indices, dir);
+            let ancestors =
+                reachable_nodes(&self.graph, &indices, dir.opposite());
+            if descendants
+                .iter()
+                .any(|n| !node_set.contains(n) && ancestors.contains(n))
+            {
+                return Err(DAGWouldCycle::py_err(
+                    "Contracting the nodes would cycle",
+                ));
+            }
+        }
+        let mut in_edges: Vec<(NodeIndex, PyObject)> = Vec::new();
+        let mut out_edges: Vec<(NodeIndex, PyObject)> = Vec::new();
+        for index in indices.iter() {
+            let dir = petgraph::Direction::Incoming;
+            for edge in self.graph.edges_directed(*index, dir) {
+                if !node_set.contains(&edge.source()) {
+                    in_edges.push((edge.source(), edge.weight().clone_ref(py)));
+                }
+            }
+            let dir = petgraph::Direction::Outgoing;
+            for edge in self.graph.edges_directed(*index, dir) {
+                if !node_set.contains(&edge.target()) {
+                    out_edges
+                        .push((edge.target(), edge.weight().clone_ref(py)));
+                }
+            }
+        }
+        if let Some(weight_combo_fn) = weight_combo_fn {
+            in_edges = combine_parallel_edges(py, in_edges, &weight_combo_fn)?;
+            out_edges =
+                combine_parallel_edges(py, out_edges, &weight_combo_fn)?;
+        }
+        let new_index = self.graph.add_node(obj);
+        for (source, weight) in in_edges {
+            self.graph.add_edge(source, new_index, weight);
+        }
+        for (target, weight) in out_edges {
+            self.graph.add_edge(new_index, target, weight);
+        }
+        for index in indices {
+            self.graph.remove_node(index);
+        }
+        self.rebuild_topo_order();
+        Ok(new_index.index())
+    }
+
+    pub fn substitute_node_with_subgraph(
+        &mut self,
+        py: Python,
+        node: usize,
+        other: &PyDAG,
+        edge_map_fn: PyObject,
+    ) -> PyResult<PyObject> {
+        let node_index = self.node_index(node)?;
+        // Every path through the substituted nodes enters and leaves via
+        // edges that used to be connected to node, so the only way to
+        // introduce a cycle is for other to have one.
+        if self.check_cycle && algo::is_cyclic_directed(&other.graph) {
+            return Err(DAGWouldCycle::py_err(
+                "Substituting the node would cycle",
+            ));
+        }
+        let map_edge = |source: usize, target: usize, weight: &PyObject| {
+            let res = edge_map_fn.call1(py, (source, target, weight))?;
+            other.optional_node_index(res.extract(py)?)
+        };
+        // Resolve every edge to or from node before modifying the graph so
+        // an exception from edge_map_fn leaves the graph untouched.
+        let mut in_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = Vec::new();
+        let mut out_edges: Vec<(NodeIndex, NodeIndex, PyObject)> = Vec::new();
+        let dir = petgraph::Direction::Incoming;
+        for edge in self.graph.edges_directed(node_index, dir) {
+            let source = edge.source();
+            if source == node_index {
+                continue;
+            }
+            if let Some(target) = map_edge(source.index(), node, edge.weight())?
+            {
+                in_edges.push((source, target, edge.weight().clone_ref(py)));
+            }
+        }
+        let dir = petgraph::Direction::Outgoing;
+        for edge in self.graph.edges_directed(node_index, dir) {
+            let target = edge.target();
+            if target == node_index {
+                continue;
+            }
+            if let Some(source) = map_edge(node, target.index(), edge.weight())?
+            {
+                out_edges.push((source, target, edge.weight().clone_ref(py)));
+            }
+        }
+        self.graph.remove_node(node_index);
+        let mut node_map: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let out_dict = PyDict::new(py);
+        for other_index in other.graph.node_indices() {
+            let new_index =
+                self.graph.add_node(other.graph[other_index].clone_ref(py));
+            node_map.insert(other_index, new_index);
+            out_dict.set_item(other_index.index(), new_index.index())?;
+        }
+        for edge in other.graph.edge_references() {
+            self.graph.add_edge(
+                node_map[&edge.source()],
+                node_map[&edge.target()],
+                edge.weight().clone_ref(py),
+            );
+        }
+        for (source, target, weight) in in_edges {
+            self.graph.add_edge(source, node_map[&target], weight);
+        }
+        for (source, target, weight) in out_edges {
+            self.graph.add_edge(node_map[&source], target, weight);
+        }
+        self.rebuild_topo_order();
+        Ok(out_dict.into())
+    }
+
     pub fn adj(&mut self, py: Python, node: usize) -> PyResult<PyObject> {
-        let index = NodeIndex::new(node);
+        let index = self.node_index(node)?;
         let neighbors = self.graph.neighbors(index);
         let out_dict = PyDict::new(py);
         for neighbor in neighbors {
@@ -502,7 +810,7 @@ impl PyDAG {
         node: usize,
         direction: bool,
     ) -> PyResult<PyObject> {
-        let index = NodeIndex::new(node);
+        let index = self.node_index(node)?;
         let dir = if direction {
             petgraph::Direction::Incoming
         } else {
@@ -536,53 +844,286 @@ impl PyDAG {
         Ok(out_dict.into())
     }
 
-    pub fn in_edges(&mut self, py: Python, node: usize) -> PyResult<PyObject> {
-        let index = NodeIndex::new(node);
+    pub fn in_edges(
+        &mut self,
+        py: Python,
+        node: usize,
+    ) -> PyResult<WeightedEdgeList> {
+        let index = self.node_index(node)?;
         let dir = petgraph::Direction::Incoming;
-        let mut out_list: Vec<PyObject> = Vec::new();
+        let mut out_list: Vec<(usize, usize, PyObject)> = Vec::new();
         let raw_edges = self.graph.edges_directed(index, dir);
         for edge in raw_edges {
-            let edge_w = edge.weight();
-            let triplet = (edge.source().index(), node, edge_w).to_object(py);
-            out_list.push(triplet)
+            let edge_w = edge.weight().clone_ref(py);
+            out_list.push((edge.source().index(), node, edge_w))
         }
-        Ok(PyList::new(py, out_list).into())
+        Ok(WeightedEdgeList { edges: out_list })
     }
 
-    pub fn out_edges(&mut self, py: Python, node: usize) -> PyResult<PyObject> {
-        let index = NodeIndex::new(node);
+    pub fn out_edges(
+        &mut self,
+        py: Python,
+        node: usize,
+    ) -> PyResult<WeightedEdgeList> {
+        let index = self.node_index(node)?;
         let dir = petgraph::Direction::Outgoing;
-        let mut out_list: Vec<PyObject> = Vec::new();
+        let mut out_list: Vec<(usize, usize, PyObject)> = Vec::new();
         let raw_edges = self.graph.edges_directed(index, dir);
         for edge in raw_edges {
-            let edge_w = edge.weight();
-            let triplet = (node, edge.target().index(), edge_w).to_object(py);
-            out_list.push(triplet)
+            let edge_w = edge.weight().clone_ref(py);
+            out_list.push((node, edge.target().index(), edge_w))
+        }
+        Ok(WeightedEdgeList { edges: out_list })
+    }
+
+    pub fn add_nodes_from(
+        &mut self,
+        py: Python,
+        obj_list: PyObject,
+    ) -> PyResult<PyObject> {
+        let mut out_list: Vec<usize> = Vec::new();
+        for obj in obj_list.as_ref(py).iter()? {
+            let node_index = self.graph.add_node(obj?.into());
+            self.topo_order.add_node(node_index);
+            out_list.push(node_index.index());
         }
         Ok(PyList::new(py, out_list).into())
     }
 
-    //   pub fn add_nodes_from(&self) -> PyResult<()> {
-    //
-    //   }
-    //   pub fn add_edges_from(&self) -> PyResult<()> {
-    //
-    //   }
+    #[args(atomic = "false")]
+    pub fn add_edges_from(
+        &mut self,
+        py: Python,
+        obj_list: PyObject,
+        atomic: bool,
+    ) -> PyResult<PyObject> {
+        let mut edges: Vec<(usize, usize, PyObject)> = Vec::new();
+        for obj in obj_list.as_ref(py).iter()? {
+            edges.push(obj?.extract()?);
+        }
+        let out_list = self.add_edges(py, edges, atomic)?;
+        Ok(PyList::new(py, out_list).into())
+    }
+
+    #[args(atomic = "false")]
+    pub fn add_edges_from_no_data(
+        &mut self,
+        py: Python,
+        obj_list: PyObject,
+        atomic: bool,
+    ) -> PyResult<PyObject> {
+        let mut edges: Vec<(usize, usize, PyObject)> = Vec::new();
+        for obj in obj_list.as_ref(py).iter()? {
+            let (parent, child): (usize, usize) = obj?.extract()?;
+            edges.push((parent, child, py.None()));
+        }
+        let out_list = self.add_edges(py, edges, atomic)?;
+        Ok(PyList::new(py, out_list).into())
+    }
+
     //   pub fn number_of_edges(&self) -> PyResult<()> {
     //
     //   }
-    pub fn in_degree(&self, node: usize) -> usize {
-        let index = NodeIndex::new(node);
+    pub fn in_degree(&self, node: usize) -> PyResult<usize> {
+        let index = self.node_index(node)?;
         let dir = petgraph::Direction::Incoming;
         let neighbors = self.graph.edges_directed(index, dir);
-        neighbors.count()
+        Ok(neighbors.count())
     }
 
-    pub fn out_degree(&self, node: usize) -> usize {
-        let index = NodeIndex::new(node);
+    pub fn out_degree(&self, node: usize) -> PyResult<usize> {
+        let index = self.node_index(node)?;
         let dir = petgraph::Direction::Outgoing;
         let neighbors = self.graph.edges_directed(index, dir);
-        neighbors.count()
+        Ok(neighbors.count())
+    }
+}
+
+impl PyDAG {
+    fn new_like(
+        &self,
+        py: Python,
+        graph: StableDiGraph<PyObject, PyObject>,
+    ) -> PyDAG {
+        let mut cycle_state = algo::DfsSpace::default();
+        let topo_order = TopoOrder::from_graph(&graph, Some(&mut cycle_state));
+        PyDAG {
+            graph,
+            cycle_state,
+            topo_order,
+            check_cycle: self.check_cycle,
+            attrs: self.attrs.clone_ref(py),
+        }
+    }
+
+    fn rebuild_topo_order(&mut self) {
+        self.topo_order =
+            TopoOrder::from_graph(&self.graph, Some(&mut self.cycle_state));
+    }
+
+    /// Add an edge, checking for a cycle if check_cycle is set, and keep the
+    /// topological order up to date.
+    fn add_edge_checked(
+        &mut self,
+        p_index: NodeIndex,
+        c_index: NodeIndex,
+        edge: PyObject,
+    ) -> PyResult<EdgeIndex> {
+        if self.check_cycle {
+            // The order is only invalid if the graph already has a cycle, so
+            // no edge could be checked.
+            if !self.topo_order.is_valid() {
+                return Err(DAGHasCycle::py_err("PyDAG object has a cycle"));
+            }
+            if !self.topo_order.add_edge(&self.graph, p_index, c_index) {
+                return Err(DAGWouldCycle::py_err(
+                    "Adding an edge would cycle",
+                ));
+            }
+        } else {
+            self.topo_order.edge_added(p_index, c_index);
+        }
+        Ok(self.graph.add_edge(p_index, c_index, edge))
+    }
+
+    fn node_index(&self, node: usize) -> PyResult<NodeIndex> {
+        let index = NodeIndex::new(node);
+        if index.index() != node || !self.graph.contains_node(index) {
+            let gil = Python::acquire_gil();
+            return Err(node_not_found(gil.python(), node));
+        }
+        Ok(index)
+    }
+
+    fn optional_node_index(
+        &self,
+        node: Option<usize>,
+    ) -> PyResult<Option<NodeIndex>> {
+        match node {
+            Some(node) => Ok(Some(self.node_index(node)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn edge_index(&self, edge: usize) -> PyResult<EdgeIndex> {
+        let index = EdgeIndex::new(edge);
+        if index.index() != edge || self.graph.edge_weight(index).is_none() {
+            let gil = Python::acquire_gil();
+            return Err(edge_not_found(gil.python(), edge));
+        }
+        Ok(index)
+    }
+
+    fn add_edges(
+        &mut self,
+        py: Python,
+        edges: Vec<(usize, usize, PyObject)>,
+        atomic: bool,
+    ) -> PyResult<Vec<usize>> {
+        // Check every endpoint before adding anything so an invalid index
+        // doesn't leave part of the batch in the graph.
+        let mut new_edges: Vec<(NodeIndex, NodeIndex, PyObject)> =
+            Vec::with_capacity(edges.len());
+        for (parent, child, edge) in edges {
+            let p_index = self.node_index(parent)?;
+            let c_index = self.node_index(child)?;
+            new_edges.push((p_index, c_index, edge));
+        }
+        if !self.check_cycle {
+            let mut out_list: Vec<usize> = Vec::with_capacity(new_edges.len());
+            for (p_index, c_index, edge) in new_edges {
+                let edge_index =
+                    self.add_edge_checked(p_index, c_index, edge)?;
+                out_list.push(edge_index.index());
+            }
+            return Ok(out_list);
+        }
+        if atomic {
+            return self.add_edges_atomic(py, new_edges);
+        }
+        let mut added: Vec<EdgeIndex> = Vec::with_capacity(new_edges.len());
+        for (p_index, c_index, edge) in new_edges {
+            added.push(self.graph.add_edge(p_index, c_index, edge));
+        }
+        let topo_order =
+            TopoOrder::from_graph(&self.graph, Some(&mut self.cycle_state));
+        if topo_order.is_valid() {
+            self.topo_order = topo_order;
+            return Ok(added.iter().map(|edge| edge.index()).collect());
+        }
+        // At least one edge in the batch introduced a cycle. Remove the
+        // batch and replay it one edge at a time so the edges before the
+        // offending one are kept, like repeated add_edge() calls would.
+        let mut edges: Vec<(NodeIndex, NodeIndex, PyObject)> =
+            Vec::with_capacity(added.len());
+        for edge_index in added.into_iter().rev() {
+            let (p_index, c_index) =
+                self.graph.edge_endpoints(edge_index).unwrap();
+            let edge = self.graph.remove_edge(edge_index).unwrap();
+            edges.push((p_index, c_index, edge));
+        }
+        let mut out_list: Vec<usize> = Vec::with_capacity(edges.len());
+        for (p_index, c_index, edge) in edges.into_iter().rev() {
+            let edge_index = self.add_edge_checked(p_index, c_index, edge)?;
+            out_list.push(edge_index.index());
+        }
+        Ok(out_list)
+    }
+
+    /// Add all the edges and check for a cycle once. If there is a cycle
+    /// every new edge is removed again and the first edge of the batch that
+    /// closes a cycle is reported.
+    fn add_edges_atomic(
+        &mut self,
+        py: Python,
+        edges: Vec<(NodeIndex, NodeIndex, PyObject)>,
+    ) -> PyResult<Vec<usize>> {
+        if !self.topo_order.is_valid() {
+            return Err(DAGHasCycle::py_err("PyDAG object has a cycle"));
+        }
+        let mut new_edges: Vec<EdgeIndex> = Vec::with_capacity(edges.len());
+        for (p_index, c_index, edge) in edges {
+            new_edges.push(self.graph.add_edge(p_index, c_index, edge));
+        }
+        let topo_order =
+            TopoOrder::from_graph(&self.graph, Some(&mut self.cycle_state));
+        if topo_order.is_valid() {
+            self.topo_order = topo_order;
+            return Ok(new_edges.iter().map(|edge| edge.index()).collect());
+        }
+        // Remove the edges in reverse order so the edge indices are freed
+        // in the order they were taken.
+        let mut endpoints: Vec<(NodeIndex, NodeIndex)> =
+            Vec::with_capacity(new_edges.len());
+        for edge in new_edges.iter().rev() {
+            endpoints.push(self.graph.edge_endpoints(*edge).unwrap());
+            self.graph.remove_edge(*edge);
+        }
+        endpoints.reverse();
+        // Replay the batch without data to find the offending edge
+        let mut replayed: Vec<EdgeIndex> = Vec::new();
+        let mut offending: Option<(NodeIndex, NodeIndex)> = None;
+        for (p_index, c_index) in endpoints {
+            let state = Some(&mut self.cycle_state);
+            if algo::has_path_connecting(&self.graph, c_index, p_index, state) {
+                offending = Some((p_index, c_index));
+                break;
+            }
+            replayed.push(self.graph.add_edge(p_index, c_index, py.None()));
+        }
+        for edge in replayed.into_iter().rev() {
+            self.graph.remove_edge(edge);
+        }
+        match offending {
+            Some((p_index, c_index)) => Err(DAGWouldCycle::py_err(format!(
+                "Adding the edge ({}, {}) would cycle",
+                p_index.index(),
+                c_index.index()
+            ))),
+            // None of the new edges closes a cycle so the graph already had
+            // one before the batch was added.
+            None => Err(DAGHasCycle::py_err("PyDAG object has a cycle")),
+        }
     }
 }
 
@@ -591,21 +1132,117 @@ impl PyMappingProtocol for PyDAG {
     fn __len__(&self) -> PyResult<usize> {
         Ok(self.graph.node_count())
     }
+
+    fn __getitem__(&'p self, idx: usize) -> PyResult<&'p PyObject> {
+        let index = self.node_index(idx)?;
+        Ok(&self[index])
+    }
+
+    fn __setitem__(&'p mut self, idx: usize, value: PyObject) -> PyResult<()> {
+        let index = self.node_index(idx)?;
+        self[index] = value;
+        Ok(())
+    }
+
+    fn __delitem__(&'p mut self, idx: usize) -> PyResult<()> {
+        self.remove_node(idx)
+    }
 }
 
-fn must_check_for_cycle(dag: &PyDAG, a: NodeIndex, b: NodeIndex) -> bool {
-    let mut parents_a = dag
-        .graph
-        .neighbors_directed(a, petgraph::Direction::Incoming);
-    let mut children_b = dag
-        .graph
-        .neighbors_directed(b, petgraph::Direction::Outgoing);
-    parents_a.next().is_some()
-        && children_b.next().is_some()
-        && dag.graph.find_edge(a, b).is_none()
+#[pyproto]
+impl PySequenceProtocol for PyDAG {
+    fn __contains__(&self, idx: &'p PyAny) -> PyResult<bool> {
+        // Anything that isn't a valid node index is just not in the graph
+        let idx: usize = match idx.extract() {
+            Ok(idx) => idx,
+            Err(_err) => return Ok(false),
+        };
+        let index = NodeIndex::new(idx);
+        Ok(index.index() == idx && self.graph.contains_node(index))
+    }
+}
+
+#[pyproto]
+impl PyIterProtocol for PyDAG {
+    fn __iter__(slf: PyRefMut<Self>) -> PyResult<PyObject> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let mut out: Vec<usize> = Vec::new();
+        for node in slf.graph.node_indices() {
+            out.push(node.index());
+        }
+        let iter = PyList::new(py, out).call_method0("__iter__")?;
+        Ok(iter.to_object(py))
+    }
+}
+
+fn reverse_edges(graph: &mut StableDiGraph<PyObject, PyObject>) {
+    // Edges are removed from the highest index down and added back in
+    // ascending order so every edge gets its original index back from the
+    // free list.
+    let edge_indices: Vec<EdgeIndex> = graph.edge_indices().collect();
+    let mut edges: Vec<(NodeIndex, NodeIndex, PyObject)> =
+        Vec::with_capacity(edge_indices.len());
+    for edge_index in edge_indices.into_iter().rev() {
+        let (source, target) = graph.edge_endpoints(edge_index).unwrap();
+        let weight = graph.remove_edge(edge_index).unwrap();
+        edges.push((target, source, weight));
+    }
+    for (source, target, weight) in edges.into_iter().rev() {
+        graph.add_edge(source, target, weight);
+    }
+}
+
+/// Find the nodes reachable from ``sources`` by following edges in ``dir``.
+/// A source is only included if it can be reached from one of the sources.
+fn reachable_nodes(
+    graph: &StableDiGraph<PyObject, PyObject>,
+    sources: &[NodeIndex],
+    dir: petgraph::Direction,
+) -> HashSet<NodeIndex> {
+    let mut seen: HashSet<NodeIndex> = HashSet::new();
+    let mut queue: VecDeque<NodeIndex> = sources.iter().cloned().collect();
+    while let Some(node) = queue.pop_front() {
+        for neighbor in graph.neighbors_directed(node, dir) {
+            if seen.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    seen
+}
+
+fn combine_parallel_edges(
+    py: Python,
+    edges: Vec<(NodeIndex, PyObject)>,
+    weight_combo_fn: &PyObject,
+) -> PyResult<Vec<(NodeIndex, PyObject)>> {
+    let mut positions: HashMap<NodeIndex, usize> = HashMap::new();
+    let mut out: Vec<(NodeIndex, PyObject)> = Vec::new();
+    for (node, weight) in edges {
+        match positions.get(&node) {
+            Some(position) => {
+                let combined =
+                    weight_combo_fn.call1(py, (&out[*position].1, &weight))?;
+                out[*position].1 = combined;
+            }
+            None => {
+                positions.insert(node, out.len());
+                out.push((node, weight));
+            }
+        }
+    }
+    Ok(out)
 }
 
-fn longest_path(graph: &PyDAG) -> PyResult<Vec<usize>> {
+fn longest_path<F, T>(
+    graph: &PyDAG,
+    mut weight_fn: F,
+) -> PyResult<(Vec<usize>, T)>
+where
+    F: FnMut(EdgeIndex) -> PyResult<T>,
+    T: Copy + Default + PartialOrd + Add<Output = T>,
+{
     let dag = &graph.graph;
     let mut path: Vec<usize> = Vec::new();
     let nodes = match algo::toposort(graph, None) {
@@ -615,32 +1252,39 @@ fn longest_path(graph: &PyDAG) -> PyResult<Vec<usize>> {
         }
     };
     if nodes.is_empty() {
-        return Ok(path);
+        return Ok((path, T::default()));
     }
-    let mut dist: HashMap<NodeIndex, (usize, NodeIndex)> = HashMap::new();
+    let mut dist: HashMap<NodeIndex, (T, NodeIndex)> = HashMap::new();
     for node in nodes {
-        let parents =
-            dag.neighbors_directed(node, petgraph::Direction::Incoming);
-        let mut us: Vec<(usize, NodeIndex)> = Vec::new();
-        for p_node in parents {
-            let length = dist[&p_node].0 + 1;
-            us.push((length, p_node));
-        }
-        let maxu: (usize, NodeIndex);
-        if !us.is_empty() {
-            maxu = *us.iter().max_by_key(|x| x.0).unwrap();
-        } else {
-            maxu = (0, node);
+        let mut maxu: Option<(T, NodeIndex)> = None;
+        for edge in dag.edges_directed(node, petgraph::Direction::Incoming) {
+            let p_node = edge.source();
+            let length = dist[&p_node].0 + weight_fn(edge.id())?;
+            maxu = match maxu {
+                Some(current) if length < current.0 => Some(current),
+                _ => Some((length, p_node)),
+            };
+        }
+        // A path can always start at the node itself, so never keep a
+        // predecessor that would make the path shorter than that.
+        let maxu = match maxu {
+            Some(maxu) if maxu.0 >= T::default() => maxu,
+            _ => (T::default(), node),
         };
         dist.insert(node, maxu);
     }
-    let first = match dist.keys().max_by_key(|index| dist[index]) {
-        Some(first) => first,
+    let first = dist.iter().max_by(|a, b| {
+        ((a.1).0, (a.1).1, a.0)
+            .partial_cmp(&((b.1).0, (b.1).1, b.0))
+            .unwrap_or(Ordering::Equal)
+    });
+    let (first, length) = match first {
+        Some((first, (length, _))) => (*first, *length),
         None => {
             return Err(Exception::py_err("Encountered something unexpected"))
         }
     };
-    let mut v = *first;
+    let mut v = first;
     let mut u: Option<NodeIndex> = None;
     while match u {
         Some(u) => u != v,
@@ -651,31 +1295,179 @@ fn longest_path(graph: &PyDAG) -> PyResult<Vec<usize>> {
         v = dist[&v].1;
     }
     path.reverse();
-    Ok(path)
+    Ok((path, length))
 }
 
-#[pyfunction]
-fn dag_longest_path(py: Python, graph: &PyDAG) -> PyResult<PyObject> {
-    let path = longest_path(graph)?;
+/// Find the longest path in the DAG using the python callable ``weight_fn``
+/// for the edge weights. The length is returned as an int if every weight
+/// is an int, otherwise it is returned as a float.
+fn weighted_longest_path(
+    py: Python,
+    graph: &PyDAG,
+    weight_fn: PyObject,
+) -> PyResult<(Vec<usize>, PyObject)> {
+    let mut weights: HashMap<EdgeIndex, PyObject> = HashMap::new();
+    let mut all_int = true;
+    for edge in graph.graph.edge_references() {
+        let weight = weight_fn.call1(
+            py,
+            (edge.source().index(), edge.target().index(), edge.weight()),
+        )?;
+        all_int = all_int && weight.as_ref(py).downcast_ref::<PyLong>().is_ok();
+        weights.insert(edge.id(), weight);
+    }
+    if all_int {
+        let (path, length) =
+            longest_path(graph, |edge| weights[&edge].extract::<i64>(py))?;
+        Ok((path, length.into_py(py)))
+    } else {
+        let (path, length) =
+            longest_path(graph, |edge| weights[&edge].extract::<f64>(py))?;
+        Ok((path, length.into_py(py)))
+    }
+}
+
+#[pyfunction(weight_fn = "None")]
+fn dag_longest_path(
+    py: Python,
+    graph: &PyDAG,
+    weight_fn: Option<PyObject>,
+) -> PyResult<PyObject> {
+    let path = match weight_fn {
+        Some(weight_fn) => weighted_longest_path(py, graph, weight_fn)?.0,
+        None => longest_path(graph, |_| Ok(1usize))?.0,
+    };
     Ok(PyList::new(py, path).into())
 }
 
-#[pyfunction]
-fn dag_longest_path_length(graph: &PyDAG) -> PyResult<usize> {
-    let path = longest_path(graph)?;
-    if path.is_empty() {
-        return Ok(0);
+#[pyfunction(weight_fn = "None")]
+fn dag_longest_path_length(
+    py: Python,
+    graph: &PyDAG,
+    weight_fn: Option<PyObject>,
+) -> PyResult<PyObject> {
+    match weight_fn {
+        Some(weight_fn) => Ok(weighted_longest_path(py, graph, weight_fn)?.1),
+        None => Ok(longest_path(graph, |_| Ok(1usize))?.1.into_py(py)),
     }
-    let path_length: usize = path.len() - 1;
-    Ok(path_length)
 }
 
-#[pyfunction]
-fn number_weakly_connected_components(graph: &PyDAG) -> usize {
-    algo::connected_components(graph)
-}
+/// A node with its earliest start, latest start and slack
+type NodeTimes<T> = (NodeIndex, T, T, T);
 
-#[pyfunction]
+/// Compute the earliest start, latest start and slack of every node in the
+/// DAG where ``duration_fn`` returns the duration of a node. Returns the
+/// times in topological order and a critical path.
+fn critical_path<F, T>(
+    dag: &PyDAG,
+    mut duration_fn: F,
+) -> PyResult<(Vec<NodeTimes<T>>, Vec<usize>)>
+where
+    F: FnMut(NodeIndex) -> PyResult<T>,
+    T: Copy + Default + PartialOrd + Add<Output = T> + Sub<Output = T>,
+{
+    let nodes = match algo::toposort(dag, None) {
+        Ok(nodes) => nodes,
+        Err(_err) => {
+            return Err(DAGHasCycle::py_err("Sort encountered a cycle"))
+        }
+    };
+    let mut duration: HashMap<NodeIndex, T> = HashMap::new();
+    // The earliest start of each node and the predecessor that sets it
+    let mut earliest: HashMap<NodeIndex, (T, Option<NodeIndex>)> =
+        HashMap::new();
+    let mut last: Option<(T, NodeIndex)> = None;
+    for node in nodes.iter() {
+        let node_duration = duration_fn(*node)?;
+        duration.insert(*node, node_duration);
+        let mut start: (T, Option<NodeIndex>) = (T::default(), None);
+        let dir = petgraph::Direction::Incoming;
+        for parent in dag.graph.neighbors_directed(*node, dir) {
+            let finish = earliest[&parent].0 + duration[&parent];
+            if start.1.is_none() || finish > start.0 {
+                start = (finish, Some(parent));
+            }
+        }
+        earliest.insert(*node, start);
+        let finish = start.0 + node_duration;
+        last = match last {
+            Some(current) if finish <= current.0 => Some(current),
+            _ => Some((finish, *node)),
+        };
+    }
+    let total = last.map_or(T::default(), |last| last.0);
+    let mut latest: HashMap<NodeIndex, T> = HashMap::new();
+    for node in nodes.iter().rev() {
+        let mut finish = total;
+        for child in dag.graph.neighbors(*node) {
+            if latest[&child] < finish {
+                finish = latest[&child];
+            }
+        }
+        latest.insert(*node, finish - duration[node]);
+    }
+    let mut path: Vec<usize> = Vec::new();
+    let mut current = last.map(|last| last.1);
+    while let Some(node) = current {
+        path.push(node.index());
+        current = earliest[&node].1;
+    }
+    path.reverse();
+    let times = nodes
+        .iter()
+        .map(|node| {
+            let start = earliest[node].0;
+            (*node, start, latest[node], latest[node] - start)
+        })
+        .collect();
+    Ok((times, path))
+}
+
+fn critical_path_dict<T: IntoPy<PyObject>>(
+    py: Python,
+    times: Vec<NodeTimes<T>>,
+) -> PyResult<PyObject> {
+    let out_dict = PyDict::new(py);
+    for (node, earliest, latest, slack) in times {
+        let node_times =
+            (earliest.into_py(py), latest.into_py(py), slack.into_py(py));
+        out_dict.set_item(node.index(), node_times)?;
+    }
+    Ok(out_dict.into())
+}
+
+#[pyfunction]
+fn critical_path_analysis(
+    py: Python,
+    dag: &PyDAG,
+    duration_fn: PyObject,
+) -> PyResult<(PyObject, PyObject)> {
+    let mut durations: HashMap<NodeIndex, PyObject> = HashMap::new();
+    let mut all_int = true;
+    for node in dag.graph.node_indices() {
+        let duration = duration_fn.call1(py, (&dag.graph[node],))?;
+        all_int =
+            all_int && duration.as_ref(py).downcast_ref::<PyLong>().is_ok();
+        durations.insert(node, duration);
+    }
+    let (times, path) = if all_int {
+        let (times, path) =
+            critical_path(dag, |node| durations[&node].extract::<i64>(py))?;
+        (critical_path_dict(py, times)?, path)
+    } else {
+        let (times, path) =
+            critical_path(dag, |node| durations[&node].extract::<f64>(py))?;
+        (critical_path_dict(py, times)?, path)
+    };
+    Ok((times, PyList::new(py, path).into()))
+}
+
+#[pyfunction]
+fn number_weakly_connected_components(graph: &PyDAG) -> usize {
+    algo::connected_components(graph)
+}
+
+#[pyfunction]
 fn is_directed_acyclic_graph(graph: &PyDAG) -> bool {
     let cycle_detected = algo::is_cyclic_directed(graph);
     !cycle_detected
@@ -710,165 +1502,1172 @@ fn is_isomorphic_node_match(
 }
 
 #[pyfunction]
-fn topological_sort(py: Python, graph: &PyDAG) -> PyResult<PyObject> {
-    let nodes = match algo::toposort(graph, None) {
+fn topological_sort(graph: &PyDAG) -> PyResult<NodeIndices> {
+    let nodes = match graph.topo_order.nodes() {
+        Some(nodes) => nodes,
+        None => match algo::toposort(graph, None) {
+            Ok(nodes) => nodes,
+            Err(_err) => {
+                return Err(DAGHasCycle::py_err("Sort encountered a cycle"))
+            }
+        },
+    };
+    let mut out: Vec<usize> = Vec::new();
+    for node in nodes {
+        out.push(node.index());
+    }
+    Ok(NodeIndices { nodes: out })
+}
+
+/// Compute the set of descendants (``Outgoing``) or ancestors (``Incoming``)
+/// of every node in the DAG. The returned vector is indexed by node index.
+fn reachable_sets(
+    dag: &PyDAG,
+    dir: petgraph::Direction,
+) -> PyResult<Vec<FixedBitSet>> {
+    let mut nodes = match algo::toposort(dag, None) {
         Ok(nodes) => nodes,
         Err(_err) => {
             return Err(DAGHasCycle::py_err("Sort encountered a cycle"))
         }
     };
-    let mut out: Vec<usize> = Vec::new();
+    if dir == petgraph::Direction::Outgoing {
+        nodes.reverse();
+    }
+    let bound = dag.graph.node_bound();
+    let mut reachable_sets: Vec<FixedBitSet> =
+        vec![FixedBitSet::with_capacity(bound); bound];
     for node in nodes {
-        out.push(node.index());
+        let mut reachable = FixedBitSet::with_capacity(bound);
+        for neighbor in dag.graph.neighbors_directed(node, dir) {
+            reachable.insert(neighbor.index());
+            reachable.union_with(&reachable_sets[neighbor.index()]);
+        }
+        reachable_sets[node.index()] = reachable;
+    }
+    Ok(reachable_sets)
+}
+
+/// A new graph and the pairs of old and new indices of its nodes
+type CopiedNodes = (
+    StableDiGraph<PyObject, PyObject>,
+    Vec<(NodeIndex, NodeIndex)>,
+);
+
+/// Create a new graph with a copy of every node in ``dag``, returning the
+/// new graph and the mapping of old node indices to new ones.
+fn copy_nodes(py: Python, dag: &PyDAG) -> CopiedNodes {
+    let mut graph = StableDiGraph::<PyObject, PyObject>::new();
+    let mut node_order: Vec<(NodeIndex, NodeIndex)> = Vec::new();
+    for index in dag.graph.node_indices() {
+        let new_index = graph.add_node(dag.graph[index].clone_ref(py));
+        node_order.push((index, new_index));
+    }
+    (graph, node_order)
+}
+
+fn node_map_dict(
+    py: Python,
+    node_order: &[(NodeIndex, NodeIndex)],
+) -> PyResult<PyObject> {
+    let out_dict = PyDict::new(py);
+    for (index, new_index) in node_order {
+        out_dict.set_item(index.index(), new_index.index())?;
+    }
+    Ok(out_dict.into())
+}
+
+#[pyfunction]
+fn transitive_reduction(
+    py: Python,
+    dag: &PyDAG,
+) -> PyResult<(PyDAG, PyObject)> {
+    let descendants = reachable_sets(dag, petgraph::Direction::Outgoing)?;
+    let (mut graph, node_order) = copy_nodes(py, dag);
+    let node_map: HashMap<NodeIndex, NodeIndex> =
+        node_order.iter().cloned().collect();
+    let bound = dag.graph.node_bound();
+    for (index, new_index) in node_order.iter() {
+        // Any child reachable through another child is redundant
+        let mut redundant = FixedBitSet::with_capacity(bound);
+        for child in dag.graph.neighbors(*index) {
+            redundant.union_with(&descendants[child.index()]);
+        }
+        let mut added = FixedBitSet::with_capacity(bound);
+        for edge in dag.graph.edges(*index) {
+            let target = edge.target().index();
+            if redundant.contains(target) || added.put(target) {
+                continue;
+            }
+            graph.add_edge(
+                *new_index,
+                node_map[&edge.target()],
+                edge.weight().clone_ref(py),
+            );
+        }
+    }
+    Ok((dag.new_like(py, graph), node_map_dict(py, &node_order)?))
+}
+
+#[pyfunction]
+fn transitive_closure(py: Python, dag: &PyDAG) -> PyResult<(PyDAG, PyObject)> {
+    let descendants = reachable_sets(dag, petgraph::Direction::Outgoing)?;
+    let (mut graph, node_order) = copy_nodes(py, dag);
+    let node_map: HashMap<NodeIndex, NodeIndex> =
+        node_order.iter().cloned().collect();
+    let bound = dag.graph.node_bound();
+    for (index, new_index) in node_order.iter() {
+        // Copy the existing edges, including parallel edges, with their
+        // weights in the order they were added.
+        let mut children = FixedBitSet::with_capacity(bound);
+        let edges: Vec<_> = dag.graph.edges(*index).collect();
+        for edge in edges.into_iter().rev() {
+            children.insert(edge.target().index());
+            graph.add_edge(
+                *new_index,
+                node_map[&edge.target()],
+                edge.weight().clone_ref(py),
+            );
+        }
+        // New edges have no weight
+        for target in descendants[index.index()].ones() {
+            if !children.contains(target) {
+                let target = NodeIndex::new(target);
+                graph.add_edge(*new_index, node_map[&target], py.None());
+            }
+        }
     }
-    Ok(PyList::new(py, out).into())
+    Ok((dag.new_like(py, graph), node_map_dict(py, &node_order)?))
+}
+
+/// Split the DAG into layers starting from ``first_layer``. A node is added
+/// to the layer after the one where the last of its predecessors was found.
+fn generations(dag: &PyDAG, first_layer: Vec<NodeIndex>) -> Vec<Vec<usize>> {
+    let dir = petgraph::Direction::Outgoing;
+    let in_dir = petgraph::Direction::Incoming;
+    let mut predecessor_count: HashMap<NodeIndex, usize> = HashMap::new();
+    let mut output: Vec<Vec<usize>> = Vec::new();
+    // A descendant of another node in the first layer is already reported
+    // there, so it's never added to a later layer.
+    let first: HashSet<NodeIndex> = first_layer.iter().cloned().collect();
+    let mut cur_layer = first_layer;
+    while !cur_layer.is_empty() {
+        let mut next_layer: Vec<NodeIndex> = Vec::new();
+        for node in &cur_layer {
+            for child in dag.graph.neighbors_directed(*node, dir) {
+                if first.contains(&child) {
+                    continue;
+                }
+                let child_degree =
+                    predecessor_count.entry(child).or_insert_with(|| {
+                        dag.graph.edges_directed(child, in_dir).count()
+                    });
+                *child_degree -= 1;
+                if *child_degree == 0 {
+                    next_layer.push(child);
+                    predecessor_count.remove(&child);
+                }
+            }
+        }
+        next_layer.sort();
+        output.push(cur_layer.iter().map(|node| node.index()).collect());
+        cur_layer = next_layer;
+    }
+    output
 }
 
 #[pyfunction]
+fn layers(
+    py: Python,
+    dag: &PyDAG,
+    first_layer: Vec<usize>,
+) -> PyResult<PyObject> {
+    if algo::is_cyclic_directed(&dag.graph) {
+        return Err(DAGHasCycle::py_err("Layers encountered a cycle"));
+    }
+    // A repeated node would decrement the predecessor counts of its children
+    // more than once, so only keep the first occurrence of each node.
+    let mut seen: HashSet<NodeIndex> = HashSet::new();
+    let mut first: Vec<NodeIndex> = Vec::new();
+    for node in first_layer {
+        let index = dag.node_index(node)?;
+        if seen.insert(index) {
+            first.push(index);
+        }
+    }
+    let output = generations(dag, first);
+    Ok(PyList::new(py, output).into())
+}
+
+#[pyfunction]
+fn topological_generations(py: Python, dag: &PyDAG) -> PyResult<PyObject> {
+    let mut first: Vec<NodeIndex> = Vec::new();
+    for node in dag.graph.node_indices() {
+        if dag.in_degree(node.index())? == 0 {
+            first.push(node);
+        }
+    }
+    let output = generations(dag, first);
+    let found: usize = output.iter().map(|layer| layer.len()).sum();
+    if found != dag.graph.node_count() {
+        return Err(DAGHasCycle::py_err(
+            "Topological generations encountered a cycle",
+        ));
+    }
+    Ok(PyList::new(py, output).into())
+}
+
+/// Breadth-first search from ``node`` following edges in direction ``dir``,
+/// returning each node with a neighbor and its neighbors in that direction.
+/// Only nodes less than ``depth_limit`` edges away from ``node`` are
+/// expanded.
+fn bfs_neighbors(
+    graph: &PyDAG,
+    node: usize,
+    dir: petgraph::Direction,
+    depth_limit: Option<usize>,
+) -> PyResult<Vec<(NodeIndex, Vec<NodeIndex>)>> {
+    let index = graph.node_index(node)?;
+    let mut discovered: HashSet<NodeIndex> = HashSet::new();
+    let mut queue: VecDeque<(NodeIndex, usize)> = VecDeque::new();
+    let mut out: Vec<(NodeIndex, Vec<NodeIndex>)> = Vec::new();
+    discovered.insert(index);
+    queue.push_back((index, 0));
+    while let Some((nx, depth)) = queue.pop_front() {
+        match depth_limit {
+            Some(limit) if depth >= limit => continue,
+            _ => (),
+        }
+        let neighbors: Vec<NodeIndex> =
+            graph.graph.neighbors_directed(nx, dir).collect();
+        for neighbor in neighbors.iter() {
+            if discovered.insert(*neighbor) {
+                queue.push_back((*neighbor, depth + 1));
+            }
+        }
+        if !neighbors.is_empty() {
+            out.push((nx, neighbors));
+        }
+    }
+    Ok(out)
+}
+
+fn bfs_neighbors_data(
+    py: Python,
+    graph: &PyDAG,
+    node: usize,
+    dir: petgraph::Direction,
+    depth_limit: Option<usize>,
+) -> PyResult<PyObject> {
+    let out_list: Vec<(&PyObject, Vec<&PyObject>)> =
+        bfs_neighbors(graph, node, dir, depth_limit)?
+            .into_iter()
+            .map(|(nx, neighbors)| {
+                let data = neighbors.iter().map(|n| &graph.graph[*n]).collect();
+                (&graph.graph[nx], data)
+            })
+            .collect();
+    Ok(PyList::new(py, out_list).into())
+}
+
+fn bfs_neighbors_indices(
+    py: Python,
+    graph: &PyDAG,
+    node: usize,
+    dir: petgraph::Direction,
+    depth_limit: Option<usize>,
+) -> PyResult<PyObject> {
+    let out_list: Vec<(usize, Vec<usize>)> =
+        bfs_neighbors(graph, node, dir, depth_limit)?
+            .into_iter()
+            .map(|(nx, neighbors)| {
+                let indices = neighbors.iter().map(|n| n.index()).collect();
+                (nx.index(), indices)
+            })
+            .collect();
+    Ok(PyList::new(py, out_list).into())
+}
+
+#[pyfunction(depth_limit = "None")]
 fn bfs_successors(
     py: Python,
     graph: &PyDAG,
     node: usize,
+    depth_limit: Option<usize>,
 ) -> PyResult<PyObject> {
-    let index = NodeIndex::new(node);
-    let mut bfs = Bfs::new(graph, index);
-    let mut out_list: Vec<(&PyObject, Vec<&PyObject>)> = Vec::new();
-    while let Some(nx) = bfs.next(graph) {
-        let children = graph
-            .graph
-            .neighbors_directed(nx, petgraph::Direction::Outgoing);
-        let mut succesors: Vec<&PyObject> = Vec::new();
-        for succ in children {
-            succesors.push(graph.graph.node_weight(succ).unwrap());
+    let dir = petgraph::Direction::Outgoing;
+    bfs_neighbors_data(py, graph, node, dir, depth_limit)
+}
+
+#[pyfunction(depth_limit = "None")]
+fn bfs_predecessors(
+    py: Python,
+    graph: &PyDAG,
+    node: usize,
+    depth_limit: Option<usize>,
+) -> PyResult<PyObject> {
+    let dir = petgraph::Direction::Incoming;
+    bfs_neighbors_data(py, graph, node, dir, depth_limit)
+}
+
+#[pyfunction(depth_limit = "None")]
+fn bfs_successor_indices(
+    py: Python,
+    graph: &PyDAG,
+    node: usize,
+    depth_limit: Option<usize>,
+) -> PyResult<PyObject> {
+    let dir = petgraph::Direction::Outgoing;
+    bfs_neighbors_indices(py, graph, node, dir, depth_limit)
+}
+
+#[pyfunction(depth_limit = "None")]
+fn bfs_predecessor_indices(
+    py: Python,
+    graph: &PyDAG,
+    node: usize,
+    depth_limit: Option<usize>,
+) -> PyResult<PyObject> {
+    let dir = petgraph::Direction::Incoming;
+    bfs_neighbors_indices(py, graph, node, dir, depth_limit)
+}
+
+/// Return the nodes to start a depth-first search from, either ``source``
+/// or every node in the graph.
+fn dfs_starts(
+    graph: &PyDAG,
+    source: Option<usize>,
+) -> PyResult<Vec<NodeIndex>> {
+    match source {
+        Some(source) => Ok(vec![graph.node_index(source)?]),
+        None => Ok(graph.graph.node_indices().collect()),
+    }
+}
+
+#[pyfunction(source = "None")]
+fn dfs_edges(graph: &PyDAG, source: Option<usize>) -> PyResult<EdgeList> {
+    // This visits the nodes in the same order as petgraph's Dfs while also
+    // tracking the node each one was reached from.
+    let mut discovered: HashSet<NodeIndex> = HashSet::new();
+    let mut out_list: Vec<(usize, usize)> = Vec::new();
+    for start in dfs_starts(graph, source)? {
+        let mut stack: Vec<(Option<NodeIndex>, NodeIndex)> =
+            vec![(None, start)];
+        while let Some((parent, node)) = stack.pop() {
+            if !discovered.insert(node) {
+                continue;
+            }
+            if let Some(parent) = parent {
+                out_list.push((parent.index(), node.index()));
+            }
+            for succ in graph.graph.neighbors(node) {
+                if !discovered.contains(&succ) {
+                    stack.push((Some(node), succ));
+                }
+            }
         }
-        if !succesors.is_empty() {
-            out_list.push((graph.graph.node_weight(nx).unwrap(), succesors));
+    }
+    Ok(EdgeList { edges: out_list })
+}
+
+#[pyfunction(source = "None")]
+fn dfs_preorder_nodes(
+    graph: &PyDAG,
+    source: Option<usize>,
+) -> PyResult<NodeIndices> {
+    let mut dfs = Dfs::empty(graph);
+    let mut out_list: Vec<usize> = Vec::new();
+    for start in dfs_starts(graph, source)? {
+        dfs.move_to(start);
+        while let Some(nx) = dfs.next(graph) {
+            out_list.push(nx.index());
         }
     }
-    Ok(PyList::new(py, out_list).into())
+    Ok(NodeIndices { nodes: out_list })
 }
 
-#[pyfunction]
-fn ancestors(py: Python, graph: &PyDAG, node: usize) -> PyResult<PyObject> {
-    let index = NodeIndex::new(node);
+#[pyfunction(source = "None")]
+fn dfs_postorder_nodes(
+    graph: &PyDAG,
+    source: Option<usize>,
+) -> PyResult<NodeIndices> {
+    let mut dfs = DfsPostOrder::empty(graph);
     let mut out_list: Vec<usize> = Vec::new();
-    for n in graph.graph.node_indices() {
-        let n_int = n.index();
-        if n_int != node && algo::has_path_connecting(graph, n, index, None) {
-            out_list.push(n_int);
+    for start in dfs_starts(graph, source)? {
+        dfs.move_to(start);
+        while let Some(nx) = dfs.next(graph) {
+            out_list.push(nx.index());
         }
     }
-    Ok(PyList::new(py, out_list).into())
+    Ok(NodeIndices { nodes: out_list })
+}
+
+/// What a search should do after calling a visitor method
+#[derive(PartialEq)]
+enum Control {
+    Continue,
+    Prune,
+    Stop,
+}
+
+/// The methods of a python visitor object, any of which may be missing
+struct Visitor {
+    discover_vertex: Option<PyObject>,
+    tree_edge: Option<PyObject>,
+    non_tree_edge: Option<PyObject>,
+    finish_vertex: Option<PyObject>,
+}
+
+impl Visitor {
+    fn new(py: Python, visitor: &PyObject) -> PyResult<Self> {
+        let method = |name: &str| -> PyResult<Option<PyObject>> {
+            let visitor = visitor.as_ref(py);
+            if visitor.hasattr(name)? {
+                Ok(Some(visitor.getattr(name)?.to_object(py)))
+            } else {
+                Ok(None)
+            }
+        };
+        Ok(Visitor {
+            discover_vertex: method("discover_vertex")?,
+            tree_edge: method("tree_edge")?,
+            non_tree_edge: method("non_tree_edge")?,
+            finish_vertex: method("finish_vertex")?,
+        })
+    }
+}
+
+/// Call a visitor method, turning PruneSearch and StopSearch exceptions into
+/// the matching Control value.
+fn call_visitor(
+    py: Python,
+    method: &Option<PyObject>,
+    args: impl IntoPy<Py<PyTuple>>,
+) -> PyResult<Control> {
+    let method = match method {
+        Some(method) => method,
+        None => return Ok(Control::Continue),
+    };
+    match method.call1(py, args) {
+        Ok(_) => Ok(Control::Continue),
+        Err(err) => {
+            if err.is_instance::<PruneSearch>(py) {
+                Ok(Control::Prune)
+            } else if err.is_instance::<StopSearch>(py) {
+                Ok(Control::Stop)
+            } else {
+                Err(err)
+            }
+        }
+    }
+}
+
+fn search_sources(
+    graph: &PyDAG,
+    sources: Option<Vec<usize>>,
+) -> PyResult<Vec<NodeIndex>> {
+    match sources {
+        Some(sources) => {
+            let mut out: Vec<NodeIndex> = Vec::with_capacity(sources.len());
+            for source in sources {
+                out.push(graph.node_index(source)?);
+            }
+            Ok(out)
+        }
+        None => Ok(graph.graph.node_indices().collect()),
+    }
+}
+
+/// Copy the outgoing edges of a node so no reference into the graph is held
+/// while a visitor method runs, as the visitor is free to modify the graph.
+/// A node the visitor has already removed has no edges left to follow.
+fn out_edges(
+    py: Python,
+    graph: &PyDAG,
+    node: NodeIndex,
+) -> Vec<(NodeIndex, PyObject)> {
+    if !graph.graph.contains_node(node) {
+        return Vec::new();
+    }
+    graph
+        .graph
+        .edges(node)
+        .map(|edge| (edge.target(), edge.weight().clone_ref(py)))
+        .collect()
+}
+
+fn bfs_visit(
+    py: Python,
+    graph: &PyDAG,
+    sources: Vec<NodeIndex>,
+    visitor: &Visitor,
+) -> PyResult<Control> {
+    let mut discovered: HashSet<NodeIndex> = HashSet::new();
+    let mut queue: VecDeque<NodeIndex> = VecDeque::new();
+    for source in sources {
+        if !discovered.insert(source) {
+            continue;
+        }
+        match call_visitor(py, &visitor.discover_vertex, (source.index(),))? {
+            Control::Stop => return Ok(Control::Stop),
+            Control::Prune => {
+                let finish = (source.index(),);
+                if call_visitor(py, &visitor.finish_vertex, finish)?
+                    == Control::Stop
+                {
+                    return Ok(Control::Stop);
+                }
+            }
+            Control::Continue => queue.push_back(source),
+        }
+        while let Some(node) = queue.pop_front() {
+            for (target, weight) in out_edges(py, graph, node) {
+                let edge_tuple = (node.index(), target.index(), weight);
+                if discovered.contains(&target) {
+                    let control = call_visitor(
+                        py,
+                        &visitor.non_tree_edge,
+                        (edge_tuple,),
+                    )?;
+                    if control == Control::Stop {
+                        return Ok(Control::Stop);
+                    }
+                    continue;
+                }
+                match call_visitor(py, &visitor.tree_edge, (edge_tuple,))? {
+                    Control::Stop => return Ok(Control::Stop),
+                    Control::Prune => continue,
+                    Control::Continue => (),
+                }
+                discovered.insert(target);
+                let discover = (target.index(),);
+                match call_visitor(py, &visitor.discover_vertex, discover)? {
+                    Control::Stop => return Ok(Control::Stop),
+                    Control::Prune => {
+                        let finish = (target.index(),);
+                        if call_visitor(py, &visitor.finish_vertex, finish)?
+                            == Control::Stop
+                        {
+                            return Ok(Control::Stop);
+                        }
+                    }
+                    Control::Continue => queue.push_back(target),
+                }
+            }
+            let finish = (node.index(),);
+            if call_visitor(py, &visitor.finish_vertex, finish)?
+                == Control::Stop
+            {
+                return Ok(Control::Stop);
+            }
+        }
+    }
+    Ok(Control::Continue)
+}
+
+fn dfs_visit(
+    py: Python,
+    graph: &PyDAG,
+    sources: Vec<NodeIndex>,
+    visitor: &Visitor,
+) -> PyResult<Control> {
+    let mut discovered: HashSet<NodeIndex> = HashSet::new();
+    let mut stack: Vec<(NodeIndex, IntoIter<(NodeIndex, PyObject)>)> =
+        Vec::new();
+    for source in sources {
+        if !discovered.insert(source) {
+            continue;
+        }
+        match call_visitor(py, &visitor.discover_vertex, (source.index(),))? {
+            Control::Stop => return Ok(Control::Stop),
+            Control::Prune => {
+                let finish = (source.index(),);
+                if call_visitor(py, &visitor.finish_vertex, finish)?
+                    == Control::Stop
+                {
+                    return Ok(Control::Stop);
+                }
+                continue;
+            }
+            Control::Continue => {
+                stack.push((source, out_edges(py, graph, source).into_iter()))
+            }
+        }
+        while let Some((node, edges)) = stack.last_mut() {
+            let node = *node;
+            let (target, weight) = match edges.next() {
+                Some(edge) => edge,
+                None => {
+                    stack.pop();
+                    let finish = (node.index(),);
+                    if call_visitor(py, &visitor.finish_vertex, finish)?
+                        == Control::Stop
+                    {
+                        return Ok(Control::Stop);
+                    }
+                    continue;
+                }
+            };
+            let edge_tuple = (node.index(), target.index(), weight);
+            if discovered.contains(&target) {
+                let control =
+                    call_visitor(py, &visitor.non_tree_edge, (edge_tuple,))?;
+                if control == Control::Stop {
+                    return Ok(Control::Stop);
+                }
+                continue;
+            }
+            match call_visitor(py, &visitor.tree_edge, (edge_tuple,))? {
+                Control::Stop => return Ok(Control::Stop),
+                Control::Prune => continue,
+                Control::Continue => (),
+            }
+            discovered.insert(target);
+            match call_visitor(py, &visitor.discover_vertex, (target.index(),))?
+            {
+                Control::Stop => return Ok(Control::Stop),
+                Control::Prune => {
+                    let finish = (target.index(),);
+                    if call_visitor(py, &visitor.finish_vertex, finish)?
+                        == Control::Stop
+                    {
+                        return Ok(Control::Stop);
+                    }
+                }
+                Control::Continue => stack
+                    .push((target, out_edges(py, graph, target).into_iter())),
+            }
+        }
+    }
+    Ok(Control::Continue)
+}
+
+#[pyfunction]
+fn bfs_search(
+    py: Python,
+    graph: &PyDAG,
+    sources: Option<Vec<usize>>,
+    visitor: PyObject,
+) -> PyResult<()> {
+    let sources = search_sources(graph, sources)?;
+    let visitor = Visitor::new(py, &visitor)?;
+    bfs_visit(py, graph, sources, &visitor)?;
+    Ok(())
+}
+
+#[pyfunction]
+fn dfs_search(
+    py: Python,
+    graph: &PyDAG,
+    sources: Option<Vec<usize>>,
+    visitor: PyObject,
+) -> PyResult<()> {
+    let sources = search_sources(graph, sources)?;
+    let visitor = Visitor::new(py, &visitor)?;
+    dfs_visit(py, graph, sources, &visitor)?;
+    Ok(())
+}
+
+#[pyfunction]
+fn ancestors(py: Python, graph: &PyDAG, node: usize) -> PyResult<PyObject> {
+    let index = graph.node_index(node)?;
+    let dir = petgraph::Direction::Incoming;
+    let mut out_set = reachable_nodes(&graph.graph, &[index], dir);
+    out_set.remove(&index);
+    let out: Vec<usize> = out_set.iter().map(|n| n.index()).collect();
+    Ok(PySet::new(py, &out)?.into())
 }
 
 #[pyfunction]
 fn descendants(py: Python, graph: &PyDAG, node: usize) -> PyResult<PyObject> {
-    let index = NodeIndex::new(node);
-    let mut out_list: Vec<usize> = Vec::new();
-    for n in graph.graph.node_indices() {
-        let n_int = n.index();
-        if n_int != node && algo::has_path_connecting(graph, index, n, None) {
-            out_list.push(n_int);
+    let index = graph.node_index(node)?;
+    let dir = petgraph::Direction::Outgoing;
+    let mut out_set = reachable_nodes(&graph.graph, &[index], dir);
+    out_set.remove(&index);
+    let out: Vec<usize> = out_set.iter().map(|n| n.index()).collect();
+    Ok(PySet::new(py, &out)?.into())
+}
+
+#[pyfunction]
+fn has_path(graph: &PyDAG, source: usize, target: usize) -> PyResult<bool> {
+    let source_index = graph.node_index(source)?;
+    let target_index = graph.node_index(target)?;
+    Ok(algo::has_path_connecting(
+        graph,
+        source_index,
+        target_index,
+        None,
+    ))
+}
+
+#[pyfunction]
+fn reachable_from(
+    py: Python,
+    graph: &PyDAG,
+    sources: Vec<usize>,
+) -> PyResult<PyObject> {
+    let mut source_indices: Vec<NodeIndex> = Vec::with_capacity(sources.len());
+    for source in sources {
+        source_indices.push(graph.node_index(source)?);
+    }
+    let dir = petgraph::Direction::Outgoing;
+    let out_set = reachable_nodes(&graph.graph, &source_indices, dir);
+    let out: Vec<usize> = out_set.iter().map(|n| n.index()).collect();
+    Ok(PySet::new(py, &out)?.into())
+}
+
+/// Find the ancestors of ``node``, including the node itself as it is its own
+/// ancestor for the purposes of the LCA.
+fn ancestor_set(dag: &PyDAG, node: NodeIndex) -> FixedBitSet {
+    let dir = petgraph::Direction::Incoming;
+    let mut ancestors = FixedBitSet::with_capacity(dag.graph.node_bound());
+    ancestors.insert(node.index());
+    for ancestor in reachable_nodes(&dag.graph, &[node], dir) {
+        ancestors.insert(ancestor.index());
+    }
+    ancestors
+}
+
+/// Find the lowest nodes in ``common``, a set of common ancestors which
+/// contains every ancestor of its members.
+fn lowest_nodes(dag: &PyDAG, common: &FixedBitSet) -> Vec<usize> {
+    common
+        .ones()
+        .filter(|node| {
+            !dag.graph
+                .neighbors(NodeIndex::new(*node))
+                .any(|child| common.contains(child.index()))
+        })
+        .collect()
+}
+
+#[pyfunction]
+fn lowest_common_ancestors(
+    py: Python,
+    dag: &PyDAG,
+    node_a: usize,
+    node_b: usize,
+) -> PyResult<PyObject> {
+    let index_a = dag.node_index(node_a)?;
+    let index_b = dag.node_index(node_b)?;
+    if algo::is_cyclic_directed(&dag.graph) {
+        return Err(DAGHasCycle::py_err(
+            "Lowest common ancestors encountered a cycle",
+        ));
+    }
+    let mut common = ancestor_set(dag, index_a);
+    common.intersect_with(&ancestor_set(dag, index_b));
+    Ok(PyList::new(py, lowest_nodes(dag, &common)).into())
+}
+
+#[pyfunction(pairs = "None")]
+fn all_pairs_lowest_common_ancestors(
+    py: Python,
+    dag: &PyDAG,
+    pairs: Option<Vec<(usize, usize)>>,
+) -> PyResult<PyObject> {
+    let mut ancestors: HashMap<NodeIndex, FixedBitSet> = HashMap::new();
+    let pairs: Vec<(NodeIndex, NodeIndex)> = match pairs {
+        Some(pairs) => {
+            let mut out: Vec<(NodeIndex, NodeIndex)> = Vec::new();
+            for (node_a, node_b) in pairs {
+                out.push((dag.node_index(node_a)?, dag.node_index(node_b)?));
+            }
+            if algo::is_cyclic_directed(&dag.graph) {
+                return Err(DAGHasCycle::py_err(
+                    "Lowest common ancestors encountered a cycle",
+                ));
+            }
+            // Only the ancestors of the nodes in the pairs are computed,
+            // once for each node.
+            for (node_a, node_b) in out.iter() {
+                for node in &[*node_a, *node_b] {
+                    ancestors
+                        .entry(*node)
+                        .or_insert_with(|| ancestor_set(dag, *node));
+                }
+            }
+            out
+        }
+        None => {
+            // Every node is part of a pair, so the ancestors of all of them
+            // are found in a single pass over the topological order.
+            let dir = petgraph::Direction::Incoming;
+            let sets = reachable_sets(dag, dir)?;
+            for (index, mut set) in sets.into_iter().enumerate() {
+                let node = NodeIndex::new(index);
+                if dag.graph.contains_node(node) {
+                    set.insert(index);
+                    ancestors.insert(node, set);
+                }
+            }
+            let nodes: Vec<NodeIndex> = dag.graph.node_indices().collect();
+            let mut out: Vec<(NodeIndex, NodeIndex)> = Vec::new();
+            for (i, node_a) in nodes.iter().enumerate() {
+                for node_b in &nodes[i..] {
+                    out.push((*node_a, *node_b));
+                }
+            }
+            out
+        }
+    };
+    let out_dict = PyDict::new(py);
+    for (node_a, node_b) in pairs {
+        let mut common = ancestors[&node_a].clone();
+        common.intersect_with(&ancestors[&node_b]);
+        let lowest = lowest_nodes(dag, &common);
+        if !lowest.is_empty() {
+            out_dict.set_item((node_a.index(), node_b.index()), lowest)?;
         }
     }
-    Ok(PyList::new(py, out_list).into())
+    Ok(out_dict.into())
+}
+
+/// Return the distinct neighbors of a node in the given direction
+fn unique_neighbors(
+    dag: &PyDAG,
+    node: NodeIndex,
+    dir: petgraph::Direction,
+) -> Vec<NodeIndex> {
+    let mut seen: HashSet<NodeIndex> = HashSet::new();
+    dag.graph
+        .neighbors_directed(node, dir)
+        .filter(|neighbor| seen.insert(*neighbor))
+        .collect()
 }
 
 #[pyfunction]
-fn lexicographical_topological_sort(
+fn collect_runs(
     py: Python,
     dag: &PyDAG,
-    key: PyObject,
+    filter_fn: PyObject,
 ) -> PyResult<PyObject> {
-    let key_callable = |a: &PyObject| -> PyResult<PyObject> {
-        let res = key.call1(py, (a,))?;
-        Ok(res.to_object(py))
+    let nodes = match algo::toposort(dag, None) {
+        Ok(nodes) => nodes,
+        Err(_err) => {
+            return Err(DAGHasCycle::py_err("Sort encountered a cycle"))
+        }
     };
-    // HashMap of node_index indegree
+    let mut matches: HashSet<NodeIndex> = HashSet::new();
+    for node in nodes.iter() {
+        let res: bool =
+            filter_fn.call1(py, (&dag.graph[*node],))?.extract(py)?;
+        if res {
+            matches.insert(*node);
+        }
+    }
+    let mut seen: HashSet<NodeIndex> = HashSet::new();
+    let mut out_list: Vec<PyObject> = Vec::new();
+    for node in nodes {
+        if !matches.contains(&node) || seen.contains(&node) {
+            continue;
+        }
+        seen.insert(node);
+        let mut group: Vec<&PyObject> = vec![&dag.graph[node]];
+        let mut current = node;
+        loop {
+            // Only extend the run if it is the only edge out of the current
+            // node and the only edge into the next one.
+            let successors =
+                unique_neighbors(dag, current, petgraph::Direction::Outgoing);
+            if successors.len() != 1 {
+                break;
+            }
+            let next = successors[0];
+            let predecessors =
+                unique_neighbors(dag, next, petgraph::Direction::Incoming);
+            if predecessors.len() != 1
+                || !matches.contains(&next)
+                || seen.contains(&next)
+            {
+                break;
+            }
+            seen.insert(next);
+            group.push(&dag.graph[next]);
+            current = next;
+        }
+        out_list.push(PyList::new(py, group).into());
+    }
+    Ok(PyList::new(py, out_list).into())
+}
+
+/// Topologically sort the DAG, picking the lowest node index available at
+/// each step so the order is deterministic.
+fn index_topological_sort(dag: &PyDAG) -> PyResult<Vec<NodeIndex>> {
     let mut in_degree_map: HashMap<NodeIndex, usize> = HashMap::new();
+    let mut zero_indegree: BinaryHeap<Reverse<NodeIndex>> = BinaryHeap::new();
     for node in dag.graph.node_indices() {
-        in_degree_map.insert(node, dag.in_degree(node.index()));
+        let degree = dag.in_degree(node.index())?;
+        if degree == 0 {
+            zero_indegree.push(Reverse(node));
+        } else {
+            in_degree_map.insert(node, degree);
+        }
     }
-
-    #[derive(Clone, Eq, PartialEq)]
-    struct State {
-        key: String,
-        node: NodeIndex,
+    let mut out: Vec<NodeIndex> = Vec::with_capacity(dag.graph.node_count());
+    while let Some(Reverse(node)) = zero_indegree.pop() {
+        for child in dag.graph.neighbors(node) {
+            let child_degree = in_degree_map.get_mut(&child).unwrap();
+            *child_degree -= 1;
+            if *child_degree == 0 {
+                zero_indegree.push(Reverse(child));
+                in_degree_map.remove(&child);
+            }
+        }
+        out.push(node);
+    }
+    if out.len() != dag.graph.node_count() {
+        return Err(DAGHasCycle::py_err("Sort encountered a cycle"));
     }
+    Ok(out)
+}
 
-    impl Ord for State {
-        fn cmp(&self, other: &State) -> Ordering {
-            // Notice that the we flip the ordering on costs.
-            // In case of a tie we compare positions - this step is necessary
-            // to make implementations of `PartialEq` and `Ord` consistent.
-            other
-                .key
-                .cmp(&self.key)
-                .then_with(|| self.node.index().cmp(&other.node.index()))
+#[pyfunction]
+fn collect_bicolor_runs(
+    py: Python,
+    dag: &PyDAG,
+    filter_fn: PyObject,
+    color_fn: PyObject,
+) -> PyResult<PyObject> {
+    let nodes = index_topological_sort(dag)?;
+    // Nodes waiting on each color for a second color to form a block
+    let mut pending: HashMap<usize, Vec<&PyObject>> = HashMap::new();
+    // The block currently open on each color
+    let mut block_id: HashMap<usize, usize> = HashMap::new();
+    let mut blocks: Vec<Vec<&PyObject>> = Vec::new();
+    let mut block_colors: Vec<(usize, usize)> = Vec::new();
+    // Closing a block on one of its colors closes it on both, so no more
+    // nodes can be added to it through the other color.
+    let close_block = |block_id: &mut HashMap<usize, usize>,
+                       block_colors: &[(usize, usize)],
+                       color: usize| {
+        if let Some(block) = block_id.remove(&color) {
+            let (c0, c1) = block_colors[block];
+            for other in &[c0, c1] {
+                if block_id.get(other) == Some(&block) {
+                    block_id.remove(other);
+                }
+            }
+        }
+    };
+    for node in nodes {
+        let is_match: Option<bool> =
+            filter_fn.call1(py, (&dag.graph[node],))?.extract(py)?;
+        let is_match = match is_match {
+            Some(is_match) => is_match,
+            None => continue,
+        };
+        let mut colors: Vec<usize> = Vec::new();
+        let out_edges = dag.graph.edges(node);
+        let in_edges = dag
+            .graph
+            .edges_directed(node, petgraph::Direction::Incoming);
+        for edge in out_edges.chain(in_edges) {
+            let color: Option<usize> =
+                color_fn.call1(py, (edge.weight(),))?.extract(py)?;
+            if let Some(color) = color {
+                if !colors.contains(&color) {
+                    colors.push(color);
+                }
+            }
+        }
+        if !is_match || colors.len() > 2 {
+            // The node breaks every run going through its colors
+            for color in colors {
+                close_block(&mut block_id, &block_colors, color);
+                pending.remove(&color);
+            }
+            continue;
+        }
+        let node_data = &dag.graph[node];
+        if colors.len() == 1 {
+            match block_id.get(&colors[0]) {
+                Some(block) => blocks[*block].push(node_data),
+                None => pending.entry(colors[0]).or_default().push(node_data),
+            }
+        } else if colors.len() == 2 {
+            let (c0, c1) = (colors[0], colors[1]);
+            match (block_id.get(&c0).cloned(), block_id.get(&c1).cloned()) {
+                (Some(b0), Some(b1)) if b0 == b1 => {
+                    blocks[b0].push(node_data);
+                }
+                _ => {
+                    close_block(&mut block_id, &block_colors, c0);
+                    close_block(&mut block_id, &block_colors, c1);
+                    let mut block: Vec<&PyObject> = Vec::new();
+                    block.extend(pending.remove(&c0).unwrap_or_default());
+                    block.extend(pending.remove(&c1).unwrap_or_default());
+                    block.push(node_data);
+                    block_id.insert(c0, blocks.len());
+                    block_id.insert(c1, blocks.len());
+                    block_colors.push((c0, c1));
+                    blocks.push(block);
+                }
+            }
         }
     }
+    let out_list: Vec<PyObject> = blocks
+        .into_iter()
+        .map(|block| PyList::new(py, block).into())
+        .collect();
+    Ok(PyList::new(py, out_list).into())
+}
 
-    // `PartialOrd` needs to be implemented as well.
-    impl PartialOrd for State {
-        fn partial_cmp(&self, other: &State) -> Option<Ordering> {
-            Some(self.cmp(other))
+#[pyfunction(reverse = "false")]
+fn lexicographical_topological_sort(
+    py: Python,
+    dag: &PyDAG,
+    key: PyObject,
+    reverse: bool,
+) -> PyResult<PyObject> {
+    let (in_dir, out_dir) = if reverse {
+        (petgraph::Direction::Outgoing, petgraph::Direction::Incoming)
+    } else {
+        (petgraph::Direction::Incoming, petgraph::Direction::Outgoing)
+    };
+    // Compute the key of every node once and rank the nodes by it, with
+    // ties broken by picking the highest node index first. Comparing python
+    // objects can raise, so the heap below orders nodes by their rank
+    // instead of by the keys.
+    let mut keyed: Vec<(PyObject, NodeIndex)> =
+        Vec::with_capacity(dag.graph.node_count());
+    for node in dag.graph.node_indices() {
+        keyed.push((key.call1(py, (&dag.graph[node],))?, node));
+    }
+    let mut compare_err: Option<PyErr> = None;
+    keyed.sort_by(|a, b| {
+        if compare_err.is_some() {
+            return Ordering::Equal;
         }
+        match a.0.as_ref(py).compare(&b.0) {
+            Ok(ordering) => ordering.then_with(|| b.1.cmp(&a.1)),
+            Err(err) => {
+                compare_err = Some(err);
+                Ordering::Equal
+            }
+        }
+    });
+    if let Some(err) = compare_err {
+        return Err(err);
+    }
+    let mut rank: HashMap<NodeIndex, usize> = HashMap::new();
+    for (position, (_key, node)) in keyed.iter().enumerate() {
+        rank.insert(*node, position);
     }
 
-    let mut zero_indegree = BinaryHeap::new();
-    for (node, degree) in in_degree_map.iter() {
-        if *degree == 0 {
-            let map_key_raw = key_callable(&dag.graph[*node])?;
-            let map_key: String = map_key_raw.extract(py)?;
-            zero_indegree.push(State {
-                key: map_key,
-                node: *node,
-            });
+    // HashMap of node_index indegree
+    let mut in_degree_map: HashMap<NodeIndex, usize> = HashMap::new();
+    let mut zero_indegree: BinaryHeap<Reverse<usize>> = BinaryHeap::new();
+    for node in dag.graph.node_indices() {
+        let degree = dag.graph.edges_directed(node, in_dir).count();
+        if degree == 0 {
+            zero_indegree.push(Reverse(rank[&node]));
+        } else {
+            in_degree_map.insert(node, degree);
         }
     }
-    let mut out_list: Vec<&PyObject> = Vec::new();
-    let dir = petgraph::Direction::Outgoing;
-    while let Some(State { key: _, node }) = zero_indegree.pop() {
-        let neighbors = dag.graph.neighbors_directed(node, dir);
-        for child in neighbors {
+    let mut out_list: Vec<&PyObject> = Vec::with_capacity(keyed.len());
+    while let Some(Reverse(position)) = zero_indegree.pop() {
+        let node = keyed[position].1;
+        for child in dag.graph.neighbors_directed(node, out_dir) {
             let child_degree = in_degree_map.get_mut(&child).unwrap();
             *child_degree -= 1;
             if *child_degree == 0 {
-                let map_key_raw = key_callable(&dag.graph[node])?;
-                let map_key: String = map_key_raw.extract(py)?;
-                zero_indegree.push(State {
-                    key: map_key,
-                    node: child,
-                });
+                zero_indegree.push(Reverse(rank[&child]));
                 in_degree_map.remove(&child);
             }
         }
         out_list.push(&dag.graph[node])
     }
+    if out_list.len() != dag.graph.node_count() {
+        return Err(DAGHasCycle::py_err("Sort encountered a cycle"));
+    }
     Ok(PyList::new(py, out_list).into())
 }
 
 #[pymodule]
-fn retworkx(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add("DAGWouldCycle", py.get_type::<DAGWouldCycle>())?;
+    m.add("NoEdgeBetweenNodes", py.get_type::<NoEdgeBetweenNodes>())?;
+    m.add("DAGHasCycle", py.get_type::<DAGHasCycle>())?;
+    m.add("NodeNotFound", py.get_type::<NodeNotFound>())?;
+    m.add("EdgeNotFound", py.get_type::<EdgeNotFound>())?;
+    m.add("PruneSearch", py.get_type::<PruneSearch>())?;
+    m.add("StopSearch", py.get_type::<StopSearch>())?;
     m.add_wrapped(wrap_pyfunction!(bfs_successors))?;
+    m.add_wrapped(wrap_pyfunction!(bfs_predecessors))?;
+    m.add_wrapped(wrap_pyfunction!(bfs_successor_indices))?;
+    m.add_wrapped(wrap_pyfunction!(bfs_predecessor_indices))?;
+    m.add_wrapped(wrap_pyfunction!(bfs_search))?;
+    m.add_wrapped(wrap_pyfunction!(dfs_search))?;
+    m.add_wrapped(wrap_pyfunction!(dfs_edges))?;
+    m.add_wrapped(wrap_pyfunction!(dfs_preorder_nodes))?;
+    m.add_wrapped(wrap_pyfunction!(dfs_postorder_nodes))?;
     m.add_wrapped(wrap_pyfunction!(dag_longest_path))?;
     m.add_wrapped(wrap_pyfunction!(dag_longest_path_length))?;
+    m.add_wrapped(wrap_pyfunction!(critical_path_analysis))?;
     m.add_wrapped(wrap_pyfunction!(number_weakly_connected_components))?;
     m.add_wrapped(wrap_pyfunction!(is_directed_acyclic_graph))?;
     m.add_wrapped(wrap_pyfunction!(is_isomorphic))?;
     m.add_wrapped(wrap_pyfunction!(is_isomorphic_node_match))?;
     m.add_wrapped(wrap_pyfunction!(topological_sort))?;
+    m.add_wrapped(wrap_pyfunction!(layers))?;
+    m.add_wrapped(wrap_pyfunction!(topological_generations))?;
+    m.add_wrapped(wrap_pyfunction!(transitive_reduction))?;
+    m.add_wrapped(wrap_pyfunction!(transitive_closure))?;
     m.add_wrapped(wrap_pyfunction!(descendants))?;
     m.add_wrapped(wrap_pyfunction!(ancestors))?;
+    m.add_wrapped(wrap_pyfunction!(has_path))?;
+    m.add_wrapped(wrap_pyfunction!(reachable_from))?;
+    m.add_wrapped(wrap_pyfunction!(lowest_common_ancestors))?;
+    m.add_wrapped(wrap_pyfunction!(all_pairs_lowest_common_ancestors))?;
     m.add_wrapped(wrap_pyfunction!(lexicographical_topological_sort))?;
+    m.add_wrapped(wrap_pyfunction!(collect_runs))?;
+    m.add_wrapped(wrap_pyfunction!(collect_bicolor_runs))?;
     m.add_class::<PyDAG>()?;
+    m.add_class::<graph::PyGraph>()?;
+    m.add_class::<NodeIndices>()?;
+    m.add_class::<EdgeIndices>()?;
+    m.add_class::<EdgeList>()?;
+    m.add_class::<WeightedEdgeList>()?;
     Ok(())
 }
 
 create_exception!(retworkx, DAGWouldCycle, Exception);
 create_exception!(retworkx, NoEdgeBetweenNodes, Exception);
 create_exception!(retworkx, DAGHasCycle, Exception);
+create_exception!(retworkx, NodeNotFound, IndexError);
+create_exception!(retworkx, EdgeNotFound, IndexError);
+create_exception!(retworkx, PruneSearch, Exception);
+create_exception!(retworkx, StopSearch, Exception);
+
+/// Build an exception for a missing node or edge with the offending index
+/// set as its ``index`` attribute.
+fn index_not_found(py: Python, err: PyErr, index: usize) -> PyErr {
+    let instance = err.to_object(py);
+    let instance = instance.as_ref(py);
+    if let Err(err) = instance.setattr("index", index) {
+        return err;
+    }
+    PyErr::from_instance(&instance)
+}
+
+pub fn node_not_found(py: Python, node: usize) -> PyErr {
+    index_not_found(
+        py,
+        NodeNotFound::py_err(format!("No node found for index {}", node)),
+        node,
+    )
+}
+
+pub fn edge_not_found(py: Python, edge: usize) -> PyErr {
+    index_not_found(
+        py,
+        EdgeNotFound::py_err(format!("No edge found for index {}", edge)),
+        edge,
+    )
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,220 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+// Dynamic topological order based on the algorithm from:
+// D. J. Pearce and P. H. J. Kelly. A Dynamic Topological Sort Algorithm for
+// Directed Acyclic Graphs. ACM Journal of Experimental Algorithmics, 2006.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use petgraph::algo;
+use petgraph::graph::NodeIndex;
+use petgraph::stable_graph::StableDiGraph;
+use petgraph::visit::Visitable;
+use petgraph::{Incoming, Outgoing};
+
+/// A topological order of a DAG that is updated as nodes and edges are
+/// added, only reordering the nodes between the endpoints of a new edge.
+///
+/// The order is only tracked while the graph is acyclic. Once an edge that
+/// breaks the order is added without checking it, the order is marked as
+/// invalid until it is rebuilt from the graph.
+pub struct TopoOrder {
+    position: HashMap<NodeIndex, i64>,
+    order: BTreeMap<i64, NodeIndex>,
+    front: i64,
+    back: i64,
+    valid: bool,
+}
+
+impl Default for TopoOrder {
+    fn default() -> Self {
+        TopoOrder::new()
+    }
+}
+
+impl TopoOrder {
+    pub fn new() -> Self {
+        TopoOrder {
+            position: HashMap::new(),
+            order: BTreeMap::new(),
+            front: 0,
+            back: 0,
+            valid: true,
+        }
+    }
+
+    /// Build the order for an existing graph, it will be invalid if the
+    /// graph contains a cycle.
+    pub fn from_graph<N, E>(
+        graph: &StableDiGraph<N, E>,
+        space: Option<
+            &mut algo::DfsSpace<
+                NodeIndex,
+                <StableDiGraph<N, E> as Visitable>::Map,
+            >,
+        >,
+    ) -> Self {
+        let mut topo_order = TopoOrder::new();
+        match algo::toposort(graph, space) {
+            Ok(nodes) => {
+                for node in nodes {
+                    topo_order.add_node(node);
+                }
+            }
+            Err(_err) => topo_order.invalidate(),
+        };
+        topo_order
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    pub fn invalidate(&mut self) {
+        self.valid = false;
+        self.position.clear();
+        self.order.clear();
+    }
+
+    /// Return the nodes in topological order or None if the order is invalid
+    pub fn nodes(&self) -> Option<Vec<NodeIndex>> {
+        if self.valid {
+            Some(self.order.values().cloned().collect())
+        } else {
+            None
+        }
+    }
+
+    /// Add a new node without any edges to the end of the order
+    pub fn add_node(&mut self, node: NodeIndex) {
+        if self.valid {
+            self.insert(node, self.back);
+            self.back += 1;
+        }
+    }
+
+    /// Add a new node without any edges to the start of the order
+    pub fn add_node_front(&mut self, node: NodeIndex) {
+        if self.valid {
+            self.front -= 1;
+            self.insert(node, self.front);
+        }
+    }
+
+    pub fn remove_node(&mut self, node: NodeIndex) {
+        if let Some(position) = self.position.remove(&node) {
+            self.order.remove(&position);
+        }
+    }
+
+    /// Record that the edge ``a -> b`` was added to the graph without a
+    /// cycle check, invalidating the order if the edge goes against it.
+    pub fn edge_added(&mut self, a: NodeIndex, b: NodeIndex) {
+        if self.valid && self.position[&a] >= self.position[&b] {
+            self.invalidate();
+        }
+    }
+
+    /// Update the order for the edge ``a -> b`` which is about to be added
+    /// to ``graph``. Returns false, leaving the order unchanged, if the edge
+    /// would create a cycle or the order is invalid because the graph
+    /// already contains one.
+    pub fn add_edge<N, E>(
+        &mut self,
+        graph: &StableDiGraph<N, E>,
+        a: NodeIndex,
+        b: NodeIndex,
+    ) -> bool {
+        if a == b || !self.valid {
+            return false;
+        }
+        let lower = self.position[&b];
+        let upper = self.position[&a];
+        if lower < upper {
+            // Only the nodes between b and a in the current order can be
+            // affected by the new edge.
+            let mut forward = match self.search(graph, b, a, upper, true) {
+                Some(forward) => forward,
+                None => return false,
+            };
+            let mut backward =
+                self.search(graph, a, b, lower, false).unwrap_or_default();
+            self.reorder(&mut backward, &mut forward);
+        }
+        true
+    }
+
+    fn insert(&mut self, node: NodeIndex, position: i64) {
+        self.position.insert(node, position);
+        self.order.insert(position, node);
+    }
+
+    /// Find the nodes reachable from ``start`` (following outgoing edges if
+    /// ``forward`` else incoming edges) whose position is within ``bound``.
+    /// Returns None if ``target`` is reached.
+    fn search<N, E>(
+        &self,
+        graph: &StableDiGraph<N, E>,
+        start: NodeIndex,
+        target: NodeIndex,
+        bound: i64,
+        forward: bool,
+    ) -> Option<Vec<NodeIndex>> {
+        let dir = if forward { Outgoing } else { Incoming };
+        let in_bound = |node: &NodeIndex| {
+            if forward {
+                self.position[node] < bound
+            } else {
+                self.position[node] > bound
+            }
+        };
+        let mut visited: HashSet<NodeIndex> = HashSet::new();
+        let mut stack = vec![start];
+        let mut found: Vec<NodeIndex> = Vec::new();
+        visited.insert(start);
+        while let Some(node) = stack.pop() {
+            found.push(node);
+            for next in graph.neighbors_directed(node, dir) {
+                if next == target {
+                    return None;
+                }
+                if in_bound(&next) && visited.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        Some(found)
+    }
+
+    /// Move the ``backward`` nodes before the ``forward`` nodes, reusing the
+    /// positions they occupy.
+    fn reorder(
+        &mut self,
+        backward: &mut [NodeIndex],
+        forward: &mut [NodeIndex],
+    ) {
+        backward.sort_by_key(|node| self.position[node]);
+        forward.sort_by_key(|node| self.position[node]);
+        let mut positions: Vec<i64> = backward
+            .iter()
+            .chain(forward.iter())
+            .map(|node| self.position[node])
+            .collect();
+        positions.sort_unstable();
+        for (node, position) in
+            backward.iter().chain(forward.iter()).zip(positions)
+        {
+            self.insert(*node, position);
+        }
+    }
+}
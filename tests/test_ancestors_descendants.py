@@ -22,14 +22,14 @@ class TestAncestors(unittest.TestCase):
         node_b = dag.add_child(node_a, 'b', {'a': 1})
         node_c = dag.add_child(node_b, 'c', {'a': 2})
         res = retworkx.ancestors(dag, node_c)
-        self.assertEqual([node_a, node_b], res)
+        self.assertEqual({node_a, node_b}, res)
 
     def test_no_ancestors(self):
         dag = retworkx.PyDAG()
         node_a = dag.add_node('a')
         dag.add_child(node_a, 'b', {'a': 1})
         res = retworkx.ancestors(dag, node_a)
-        self.assertEqual([], res)
+        self.assertEqual(set(), res)
 
     def test_ancestors_no_descendants(self):
         dag = retworkx.PyDAG()
@@ -37,7 +37,7 @@ class TestAncestors(unittest.TestCase):
         node_b = dag.add_child(node_a, 'b', {'a': 1})
         dag.add_child(node_b, 'c', {'b': 1})
         res = retworkx.ancestors(dag, node_b)
-        self.assertEqual([node_a], res)
+        self.assertEqual({node_a}, res)
 
 class TestDescendants(unittest.TestCase):
     def test_descendants(self):
@@ -46,13 +46,13 @@ class TestDescendants(unittest.TestCase):
         node_b = dag.add_child(node_a, 'b', {'a': 1})
         node_c = dag.add_child(node_b, 'c', {'a': 2})
         res = retworkx.descendants(dag, node_a)
-        self.assertEqual([node_b, node_c], res)
+        self.assertEqual({node_b, node_c}, res)
 
     def test_no_descendants(self):
         dag = retworkx.PyDAG()
         node_a = dag.add_node('a')
         res = retworkx.descendants(dag, node_a)
-        self.assertEqual([], res)
+        self.assertEqual(set(), res)
 
     def test_descendants_no_ancestors(self):
         dag = retworkx.PyDAG()
@@ -60,4 +60,56 @@ class TestDescendants(unittest.TestCase):
         node_b = dag.add_child(node_a, 'b', {'a': 1})
         node_c = dag.add_child(node_b, 'c', {'b': 1})
         res = retworkx.descendants(dag, node_b)
-        self.assertEqual([node_c], res)
+        self.assertEqual({node_c}, res)
+
+
+class TestReachability(unittest.TestCase):
+    def setUp(self):
+        """
+        a   d
+        |   |
+        b   e
+        |
+        c
+        """
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', {})
+        self.node_c = self.dag.add_child(self.node_b, 'c', {})
+        self.node_d = self.dag.add_node('d')
+        self.node_e = self.dag.add_child(self.node_d, 'e', {})
+
+    def test_has_path(self):
+        self.assertTrue(retworkx.has_path(self.dag, self.node_a, self.node_c))
+        self.assertFalse(retworkx.has_path(self.dag, self.node_c,
+                                           self.node_a))
+        self.assertFalse(retworkx.has_path(self.dag, self.node_a,
+                                           self.node_e))
+        self.assertTrue(retworkx.has_path(self.dag, self.node_a, self.node_a))
+
+    def test_has_path_invalid_node(self):
+        self.assertRaises(retworkx.NodeNotFound, retworkx.has_path, self.dag,
+                          self.node_a, 42)
+
+    def test_reachable_from(self):
+        self.assertEqual({self.node_c, self.node_e},
+                         retworkx.reachable_from(self.dag, [self.node_b,
+                                                            self.node_d]))
+        # b is reachable from a, the other sources are not reachable
+        self.assertEqual({self.node_b, self.node_c, self.node_e},
+                         retworkx.reachable_from(
+                             self.dag, [self.node_a, self.node_b,
+                                        self.node_d]))
+        self.assertEqual(set(), retworkx.reachable_from(self.dag, []))
+
+    def test_reachable_from_excludes_sources(self):
+        self.assertEqual(
+            retworkx.descendants(self.dag, self.node_b),
+            retworkx.reachable_from(self.dag, [self.node_b]))
+        self.assertNotIn(self.node_b,
+                         retworkx.reachable_from(self.dag, [self.node_b]))
+
+    def test_reachable_from_invalid_node(self):
+        self.assertRaises(retworkx.NodeNotFound, retworkx.reachable_from,
+                          self.dag, [self.node_a, 42])
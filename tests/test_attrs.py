@@ -0,0 +1,52 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import copy
+import pickle
+import unittest
+
+import retworkx
+
+
+class TestAttrs(unittest.TestCase):
+
+    def test_attrs_default(self):
+        dag = retworkx.PyDAG()
+        self.assertIsNone(dag.attrs)
+
+    def test_attrs_constructor(self):
+        dag = retworkx.PyDAG(attrs={'name': 'circuit'})
+        self.assertEqual({'name': 'circuit'}, dag.attrs)
+
+    def test_attrs_set(self):
+        dag = retworkx.PyDAG()
+        dag.attrs = {'global_phase': 0.5}
+        self.assertEqual({'global_phase': 0.5}, dag.attrs)
+
+    def test_attrs_pickle(self):
+        dag = retworkx.PyDAG(attrs={'name': 'circuit'})
+        dag.add_node('a')
+        res = pickle.loads(pickle.dumps(dag))
+        self.assertEqual({'name': 'circuit'}, res.attrs)
+
+    def test_attrs_copy(self):
+        attrs = {'name': 'circuit'}
+        dag = retworkx.PyDAG(attrs=attrs)
+        res = copy.copy(dag)
+        self.assertIs(attrs, res.attrs)
+
+    def test_attrs_deepcopy(self):
+        attrs = {'name': 'circuit'}
+        dag = retworkx.PyDAG(attrs=attrs)
+        res = copy.deepcopy(dag)
+        self.assertEqual(attrs, res.attrs)
+        self.assertIsNot(attrs, res.attrs)
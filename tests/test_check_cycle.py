@@ -0,0 +1,77 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import pickle
+import unittest
+
+import retworkx
+
+
+class TestCheckCycle(unittest.TestCase):
+
+    def test_check_cycle_default(self):
+        dag = retworkx.PyDAG()
+        self.assertTrue(dag.check_cycle)
+
+    def test_add_cycle_without_check(self):
+        graph = retworkx.PyDAG(check_cycle=False)
+        node_a = graph.add_node('a')
+        node_b = graph.add_child(node_a, 'b', {})
+        node_c = graph.add_child(node_b, 'c', {})
+        graph.add_edge(node_c, node_a, {})
+        self.assertFalse(retworkx.is_directed_acyclic_graph(graph))
+
+    def test_enable_check_cycle(self):
+        graph = retworkx.PyDAG(check_cycle=False)
+        node_a = graph.add_node('a')
+        node_b = graph.add_child(node_a, 'b', {})
+        node_c = graph.add_child(node_b, 'c', {})
+        graph.check_cycle = True
+        self.assertTrue(graph.check_cycle)
+        self.assertRaises(Exception, graph.add_edge, node_c, node_a, {})
+
+    def test_enable_check_cycle_on_cycle(self):
+        graph = retworkx.PyDAG(check_cycle=False)
+        node_a = graph.add_node('a')
+        node_b = graph.add_child(node_a, 'b', {})
+        graph.add_edge(node_b, node_a, {})
+        with self.assertRaises(Exception):
+            graph.check_cycle = True
+        self.assertFalse(graph.check_cycle)
+
+    def test_disable_check_cycle(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', {})
+        dag.check_cycle = False
+        dag.add_edge(node_b, node_a, {})
+        self.assertFalse(retworkx.is_directed_acyclic_graph(dag))
+
+    def test_check_cycle_pickle(self):
+        graph = retworkx.PyDAG(check_cycle=False)
+        node_a = graph.add_node('a')
+        node_b = graph.add_child(node_a, 'b', {})
+        graph.add_edge(node_b, node_a, {})
+        res = pickle.loads(pickle.dumps(graph))
+        self.assertFalse(res.check_cycle)
+        self.assertEqual(2, len(res.edges()))
+
+    def test_setstate_cycle_with_check_cycle(self):
+        dag = retworkx.PyDAG()
+        state = {'nodes': {0: 'a', 1: 'b', 2: 'c'},
+                 'edges': [(0, 1, None), (1, 0, None)],
+                 'check_cycle': True}
+        self.assertRaises(retworkx.DAGHasCycle, dag.__setstate__, state)
+        self.assertFalse(dag.check_cycle)
+        # The restored graph is still usable without the cycle check
+        dag.add_edge(1, 2, None)
+        self.assertEqual(3, len(dag.edges()))
@@ -0,0 +1,113 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestCollectBicolorRuns(unittest.TestCase):
+
+    def build_circuit(self, num_wires, gates):
+        """Build a circuit like DAG.
+
+        Each wire has an input and an output node and every gate is a tuple
+        of its name and the wires it acts on. Edges are colored by wire.
+        """
+        dag = retworkx.PyDAG()
+        last = [dag.add_node('in%s' % wire) for wire in range(num_wires)]
+        for name, wires in gates:
+            node = dag.add_node(name)
+            for wire in wires:
+                dag.add_edge(last[wire], node, wire)
+                last[wire] = node
+        for wire in range(num_wires):
+            dag.add_child(last[wire], 'out%s' % wire, wire)
+        return dag
+
+    @staticmethod
+    def filter_fn(node):
+        if node.startswith('in') or node.startswith('out'):
+            return None
+        return node != 'barrier'
+
+    def test_two_wire_block(self):
+        dag = self.build_circuit(2, [('h', [0]), ('cx', [0, 1]),
+                                     ('x', [1]), ('cx', [1, 0]),
+                                     ('z', [0])])
+        res = retworkx.collect_bicolor_runs(dag, self.filter_fn,
+                                            lambda edge: edge)
+        self.assertEqual([['h', 'cx', 'x', 'cx', 'z']], res)
+
+    def test_blocks_split_by_third_wire(self):
+        dag = self.build_circuit(3, [('cx', [0, 1]), ('cx', [1, 2]),
+                                     ('cx', [0, 1])])
+        res = retworkx.collect_bicolor_runs(dag, self.filter_fn,
+                                            lambda edge: edge)
+        self.assertEqual([['cx'], ['cx'], ['cx']], res)
+
+    def test_filtered_node_breaks_block(self):
+        dag = self.build_circuit(2, [('cx', [0, 1]), ('barrier', [0, 1]),
+                                     ('cx', [0, 1]), ('y', [1])])
+        res = retworkx.collect_bicolor_runs(dag, self.filter_fn,
+                                            lambda edge: edge)
+        self.assertEqual([['cx'], ['cx', 'y']], res)
+
+    def test_three_wire_gate_breaks_block(self):
+        dag = self.build_circuit(3, [('h', [0]), ('ccx', [0, 1, 2]),
+                                     ('cx', [0, 1])])
+        res = retworkx.collect_bicolor_runs(dag, self.filter_fn,
+                                            lambda edge: edge)
+        self.assertEqual([['cx']], res)
+
+    def test_uncolored_edges(self):
+        dag = self.build_circuit(2, [('cx', [0, 1]), ('x', [0])])
+        res = retworkx.collect_bicolor_runs(dag, self.filter_fn,
+                                            lambda edge: None)
+        self.assertEqual([], res)
+
+    def test_superseded_block_is_closed(self):
+        # The second cx closes the first block on both wires, so the x on
+        # wire 0 can't be added to it.
+        dag = self.build_circuit(3, [('cx', [0, 1]), ('cx', [1, 2]),
+                                     ('x', [0])])
+        res = retworkx.collect_bicolor_runs(dag, self.filter_fn,
+                                            lambda edge: edge)
+        self.assertEqual([['cx'], ['cx']], res)
+
+    def test_filtered_node_closes_block_on_both_colors(self):
+        dag = self.build_circuit(2, [('cx', [0, 1]), ('barrier', [0]),
+                                     ('y', [1])])
+        res = retworkx.collect_bicolor_runs(dag, self.filter_fn,
+                                            lambda edge: edge)
+        self.assertEqual([['cx']], res)
+
+    def test_incoming_colors(self):
+        # The gate has no outgoing edges so its colors come from its
+        # incoming edges.
+        dag = retworkx.PyDAG()
+        in_0 = dag.add_node('in0')
+        in_1 = dag.add_node('in1')
+        cx = dag.add_child(in_0, 'cx', 0)
+        dag.add_edge(in_1, cx, 1)
+        res = retworkx.collect_bicolor_runs(dag, self.filter_fn,
+                                            lambda edge: edge)
+        self.assertEqual([['cx']], res)
+
+    def test_cycle(self):
+        dag = retworkx.PyDAG(check_cycle=False)
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', 0)
+        dag.add_edge(node_b, node_a, 0)
+        self.assertRaises(retworkx.DAGHasCycle,
+                          retworkx.collect_bicolor_runs, dag, self.filter_fn,
+                          lambda edge: edge)
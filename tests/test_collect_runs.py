@@ -0,0 +1,75 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestCollectRuns(unittest.TestCase):
+
+    def test_linear(self):
+        dag = retworkx.PyDAG()
+        node = dag.add_node('x1')
+        for name in ['x2', 'y1', 'x3', 'x4']:
+            node = dag.add_child(node, name, None)
+        res = retworkx.collect_runs(dag, lambda x: x.startswith('x'))
+        self.assertEqual([['x1', 'x2'], ['x3', 'x4']], res)
+
+    def test_branches(self):
+        """
+        a
+        |
+        b
+        |\\
+        c d
+        |/
+        e
+        |
+        f
+        """
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', None)
+        node_c = dag.add_child(node_b, 'c', None)
+        node_d = dag.add_child(node_b, 'd', None)
+        node_e = dag.add_child(node_c, 'e', None)
+        dag.add_edge(node_d, node_e, None)
+        dag.add_child(node_e, 'f', None)
+        res = retworkx.collect_runs(dag, lambda x: True)
+        self.assertEqual(4, len(res))
+        self.assertIn(['a', 'b'], res)
+        self.assertIn(['c'], res)
+        self.assertIn(['d'], res)
+        self.assertIn(['e', 'f'], res)
+
+    def test_parallel_edges(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', None)
+        dag.add_edge(node_a, node_b, None)
+        res = retworkx.collect_runs(dag, lambda x: True)
+        self.assertEqual([['a', 'b']], res)
+
+    def test_no_matches(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        dag.add_child(node_a, 'b', None)
+        self.assertEqual([], retworkx.collect_runs(dag, lambda x: False))
+
+    def test_cycle(self):
+        dag = retworkx.PyDAG(check_cycle=False)
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', None)
+        dag.add_edge(node_b, node_a, None)
+        self.assertRaises(retworkx.DAGHasCycle, retworkx.collect_runs, dag,
+                          lambda x: True)
@@ -0,0 +1,68 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestContainer(unittest.TestCase):
+
+    def setUp(self):
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', 'edgy')
+
+    def test_getitem(self):
+        self.assertEqual('b', self.dag[self.node_b])
+
+    def test_getitem_invalid(self):
+        with self.assertRaises(IndexError):
+            self.dag[42]
+
+    def test_setitem(self):
+        self.dag[self.node_a] = 'new a'
+        self.assertEqual('new a', self.dag[self.node_a])
+        self.assertEqual(['new a', 'b'], self.dag.nodes())
+
+    def test_setitem_invalid(self):
+        with self.assertRaises(retworkx.NodeNotFound):
+            self.dag[42] = 'c'
+
+    def test_delitem(self):
+        del self.dag[self.node_a]
+        self.assertEqual(['b'], self.dag.nodes())
+        self.assertEqual([], self.dag.edges())
+
+    def test_delitem_invalid(self):
+        with self.assertRaises(retworkx.NodeNotFound):
+            del self.dag[42]
+
+    def test_contains(self):
+        self.assertIn(self.node_a, self.dag)
+        self.assertNotIn(42, self.dag)
+        self.dag.remove_node(self.node_a)
+        self.assertNotIn(self.node_a, self.dag)
+
+    def test_contains_not_an_index(self):
+        self.assertNotIn('a', self.dag)
+        self.assertNotIn(-1, self.dag)
+        self.assertNotIn(1.5, self.dag)
+        self.assertNotIn(None, self.dag)
+        self.assertNotIn(2 ** 100, self.dag)
+
+    def test_iter(self):
+        self.assertEqual([self.node_a, self.node_b], list(self.dag))
+
+    def test_iter_empty(self):
+        self.assertEqual([], list(retworkx.PyDAG()))
@@ -0,0 +1,71 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestContractNodes(unittest.TestCase):
+
+    def setUp(self):
+        # a -> b -> c -> d
+        # a -> e -------> d
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', 'ab')
+        self.node_c = self.dag.add_child(self.node_b, 'c', 'bc')
+        self.node_d = self.dag.add_child(self.node_c, 'd', 'cd')
+        self.node_e = self.dag.add_child(self.node_a, 'e', 'ae')
+        self.dag.add_edge(self.node_e, self.node_d, 'ed')
+
+    def test_contract_chain(self):
+        new = self.dag.contract_nodes([self.node_b, self.node_c], 'bc')
+        self.assertEqual('bc', self.dag[new])
+        self.assertNotIn(self.node_b, self.dag)
+        self.assertNotIn(self.node_c, self.dag)
+        self.assertEqual({self.node_a: 'ab'}, self.dag.adj_direction(new,
+                                                                     True))
+        self.assertEqual({self.node_d: 'cd'}, self.dag.adj_direction(new,
+                                                                     False))
+        self.assertEqual(4, len(self.dag.edges()))
+
+    def test_contract_would_cycle(self):
+        self.assertRaises(retworkx.DAGWouldCycle, self.dag.contract_nodes,
+                          [self.node_b, self.node_d], 'bd')
+        self.assertEqual(5, len(self.dag))
+        self.assertEqual(['ab', 'bc', 'cd', 'ae', 'ed'], self.dag.edges())
+
+    def test_contract_cycle_without_check(self):
+        self.dag.check_cycle = False
+        new = self.dag.contract_nodes([self.node_b, self.node_d], 'bd')
+        self.assertTrue(self.dag.has_edge(new, self.node_c))
+        self.assertTrue(self.dag.has_edge(self.node_c, new))
+        self.assertFalse(retworkx.is_directed_acyclic_graph(self.dag))
+
+    def test_contract_parallel_edges(self):
+        new = self.dag.contract_nodes([self.node_b, self.node_e], 'be')
+        self.assertEqual(['ab', 'ae'],
+                         sorted(self.dag.get_all_edge_data(self.node_a, new)))
+
+    def test_contract_combine_parallel_edges(self):
+        new = self.dag.contract_nodes([self.node_b, self.node_e], 'be',
+                                      weight_combo_fn=lambda x, y: x + y)
+        res = self.dag.get_all_edge_data(self.node_a, new)
+        self.assertEqual(1, len(res))
+        self.assertIn(res[0], ['abae', 'aeab'])
+
+    def test_contract_invalid_node(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.contract_nodes,
+                          [self.node_b, 42], 'b')
+        self.assertEqual(5, len(self.dag))
@@ -0,0 +1,110 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestCopy(unittest.TestCase):
+
+    def test_copy(self):
+        dag = retworkx.PyDAG(attrs='attrs')
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', 'ab')
+        dag.add_child(node_b, 'c', 'bc')
+        dag.remove_node(node_a)
+        res = dag.copy()
+        self.assertEqual(dag.nodes(), res.nodes())
+        self.assertEqual(dag.weighted_edge_list(), res.weighted_edge_list())
+        self.assertEqual(list(dag), list(res))
+        self.assertEqual('attrs', res.attrs)
+
+    def test_copy_is_independent(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        res = dag.copy()
+        res.add_child(node_a, 'b', 'ab')
+        self.assertEqual(1, len(dag))
+        self.assertEqual([], dag.edges())
+
+    def test_copy_check_cycle(self):
+        graph = retworkx.PyDAG(check_cycle=False)
+        self.assertFalse(graph.copy().check_cycle)
+
+
+class TestSubgraph(unittest.TestCase):
+
+    def test_subgraph(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', 'ab')
+        node_c = dag.add_child(node_b, 'c', 'bc')
+        dag.add_edge(node_a, node_c, 'ac')
+        res, node_map = dag.subgraph([node_a, node_c])
+        self.assertEqual(['a', 'c'], res.nodes())
+        self.assertEqual({node_a: 0, node_c: 1}, node_map)
+        self.assertEqual([(0, 1, 'ac')], res.weighted_edge_list())
+
+    def test_subgraph_parallel_edges(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', 'ab')
+        dag.add_edge(node_a, node_b, 'ab2')
+        res, _ = dag.subgraph([node_b, node_a])
+        self.assertEqual(['ab', 'ab2'], sorted(res.edges()))
+
+    def test_subgraph_empty(self):
+        dag = retworkx.PyDAG()
+        dag.add_node('a')
+        res, node_map = dag.subgraph([])
+        self.assertEqual(0, len(res))
+        self.assertEqual({}, node_map)
+
+    def test_subgraph_invalid_node(self):
+        dag = retworkx.PyDAG()
+        self.assertRaises(retworkx.NodeNotFound, dag.subgraph, [42])
+
+
+class TestReverse(unittest.TestCase):
+
+    def setUp(self):
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', 'ab')
+        self.node_c = self.dag.add_child(self.node_b, 'c', 'bc')
+
+    def test_reverse(self):
+        res = self.dag.reverse()
+        self.assertEqual([(self.node_b, self.node_a, 'ab'),
+                          (self.node_c, self.node_b, 'bc')],
+                         res.weighted_edge_list())
+        self.assertEqual([(self.node_a, self.node_b, 'ab'),
+                          (self.node_b, self.node_c, 'bc')],
+                         self.dag.weighted_edge_list())
+
+    def test_reverse_inplace(self):
+        self.assertIsNone(self.dag.reverse(inplace=True))
+        self.assertEqual([(self.node_b, self.node_a, 'ab'),
+                          (self.node_c, self.node_b, 'bc')],
+                         self.dag.weighted_edge_list())
+        self.assertEqual([self.node_c, self.node_b, self.node_a],
+                         retworkx.topological_sort(self.dag))
+
+    def test_reverse_preserves_edge_indices(self):
+        edge = self.dag.add_edge(self.node_a, self.node_c, 'ac')
+        self.dag.remove_edge_from_index(0)
+        self.dag.reverse(inplace=True)
+        self.assertEqual([1, edge], self.dag.edge_indices())
+        self.assertEqual((self.node_c, self.node_a),
+                         self.dag.get_edge_endpoints_by_index(edge))
@@ -0,0 +1,64 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestCriticalPathAnalysis(unittest.TestCase):
+
+    def setUp(self):
+        """
+          a(2)
+         /    \\
+        b(3)  c(1)
+         \\    /
+          d(4)   e(1)
+        """
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node(2)
+        self.node_b = self.dag.add_child(self.node_a, 3, None)
+        self.node_c = self.dag.add_child(self.node_a, 1, None)
+        self.node_d = self.dag.add_child(self.node_b, 4, None)
+        self.dag.add_edge(self.node_c, self.node_d, None)
+        self.node_e = self.dag.add_node(1)
+
+    def test_critical_path_analysis(self):
+        times, path = retworkx.critical_path_analysis(self.dag, lambda x: x)
+        self.assertEqual({self.node_a: (0, 0, 0),
+                          self.node_b: (2, 2, 0),
+                          self.node_c: (2, 4, 2),
+                          self.node_d: (5, 5, 0),
+                          self.node_e: (0, 8, 8)}, times)
+        self.assertEqual([self.node_a, self.node_b, self.node_d], path)
+
+    def test_float_durations(self):
+        times, path = retworkx.critical_path_analysis(
+            self.dag, lambda x: x / 2)
+        self.assertEqual((2.5, 2.5, 0.0), times[self.node_d])
+        self.assertIsInstance(times[self.node_a][0], float)
+        self.assertEqual([self.node_a, self.node_b, self.node_d], path)
+
+    def test_empty(self):
+        dag = retworkx.PyDAG()
+        self.assertEqual(({}, []),
+                         retworkx.critical_path_analysis(dag, lambda x: 1))
+
+    def test_cycle(self):
+        dag = retworkx.PyDAG(check_cycle=False)
+        node_a = dag.add_node(1)
+        node_b = dag.add_child(node_a, 1, None)
+        dag.add_edge(node_b, node_a, None)
+        self.assertRaises(retworkx.DAGHasCycle,
+                          retworkx.critical_path_analysis, dag, lambda x: x)
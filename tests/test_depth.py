@@ -67,3 +67,67 @@ class TestLongestPath(unittest.TestCase):
         self.assertEqual(0, retworkx.dag_longest_path_length(dag))
         self.assertEqual([],
                          retworkx.dag_longest_path(dag))
+
+    def test_weighted_linear(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', 1)
+        node_c = dag.add_child(node_b, 'c', 1)
+        node_d = dag.add_child(node_a, 'd', 5)
+        node_e = dag.add_child(node_d, 'e', 1)
+        dag.add_edge(node_c, node_e, 1)
+
+        def weight_fn(source, target, weight):
+            return weight
+
+        self.assertEqual(3, retworkx.dag_longest_path_length(dag))
+        self.assertEqual([node_a, node_b, node_c, node_e],
+                         retworkx.dag_longest_path(dag))
+        length = retworkx.dag_longest_path_length(dag, weight_fn=weight_fn)
+        self.assertEqual(6, length)
+        self.assertIsInstance(length, int)
+        self.assertEqual([node_a, node_d, node_e],
+                         retworkx.dag_longest_path(dag, weight_fn))
+
+    def test_weighted_float(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', 1.5)
+        node_c = dag.add_child(node_a, 'c', 0.5)
+        dag.add_edge(node_c, node_b, 0.5)
+
+        def weight_fn(source, target, weight):
+            return weight
+
+        length = retworkx.dag_longest_path_length(dag, weight_fn)
+        self.assertIsInstance(length, float)
+        self.assertEqual(1.5, length)
+        self.assertEqual([node_a, node_b],
+                         retworkx.dag_longest_path(dag, weight_fn))
+
+    def test_weighted_negative_weights(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', -5)
+        node_c = dag.add_child(node_b, 'c', 2)
+        self.assertEqual(
+            2, retworkx.dag_longest_path_length(dag, lambda *x: x[2]))
+        self.assertEqual([node_b, node_c],
+                         retworkx.dag_longest_path(dag, lambda *x: x[2]))
+
+    def test_weighted_invalid_weight(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        dag.add_child(node_a, 'b', 'not a number')
+        self.assertRaises(TypeError, retworkx.dag_longest_path_length, dag,
+                          lambda *x: x[2])
+
+    def test_cycle(self):
+        dag = retworkx.PyDAG(check_cycle=False)
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', {})
+        dag.add_edge(node_b, node_a, {})
+        self.assertRaises(retworkx.DAGHasCycle,
+                          retworkx.dag_longest_path_length, dag)
+        self.assertRaises(retworkx.DAGHasCycle,
+                          retworkx.dag_longest_path, dag, lambda *x: 1)
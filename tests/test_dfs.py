@@ -0,0 +1,92 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestDfs(unittest.TestCase):
+
+    def setUp(self):
+        """
+          a   e
+         / \\  |
+        b   c |
+        |     |
+        d <---+
+        """
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', None)
+        self.node_c = self.dag.add_child(self.node_a, 'c', None)
+        self.node_d = self.dag.add_child(self.node_b, 'd', None)
+        self.node_e = self.dag.add_parent(self.node_d, 'e', None)
+
+    def test_dfs_edges(self):
+        res = retworkx.dfs_edges(self.dag, self.node_a)
+        self.assertEqual(3, len(res))
+        self.assertEqual(
+            {(self.node_a, self.node_b), (self.node_b, self.node_d),
+             (self.node_a, self.node_c)}, set(res))
+        self.assertLess(res.index((self.node_a, self.node_b)),
+                        res.index((self.node_b, self.node_d)))
+
+    def test_dfs_edges_whole_graph(self):
+        res = retworkx.dfs_edges(self.dag)
+        self.assertEqual(
+            {(self.node_a, self.node_b), (self.node_b, self.node_d),
+             (self.node_a, self.node_c)}, set(res))
+
+    def test_dfs_preorder_nodes(self):
+        res = retworkx.dfs_preorder_nodes(self.dag, self.node_a)
+        self.assertEqual(self.node_a, res[0])
+        self.assertEqual({self.node_a, self.node_b, self.node_c,
+                          self.node_d}, set(res))
+        self.assertLess(res.index(self.node_b), res.index(self.node_d))
+        # The preorder matches the order of the tree edges
+        self.assertEqual(
+            res[1:], [edge[1] for edge in retworkx.dfs_edges(self.dag,
+                                                             self.node_a)])
+
+    def test_dfs_preorder_nodes_whole_graph(self):
+        res = retworkx.dfs_preorder_nodes(self.dag)
+        self.assertEqual(5, len(res))
+        self.assertEqual(self.node_e, res[-1])
+
+    def test_dfs_postorder_nodes(self):
+        res = retworkx.dfs_postorder_nodes(self.dag, self.node_a)
+        self.assertEqual(self.node_a, res[-1])
+        self.assertEqual({self.node_a, self.node_b, self.node_c,
+                          self.node_d}, set(res))
+        self.assertLess(res.index(self.node_d), res.index(self.node_b))
+
+    def test_dfs_postorder_nodes_whole_graph(self):
+        res = retworkx.dfs_postorder_nodes(self.dag)
+        self.assertEqual(5, len(res))
+        self.assertEqual(self.node_e, res[-1])
+        for source, target in self.dag.edge_list():
+            if source != self.node_e:
+                self.assertLess(res.index(target), res.index(source))
+
+    def test_dfs_leaf(self):
+        self.assertEqual([], retworkx.dfs_edges(self.dag, self.node_d))
+        self.assertEqual([self.node_d],
+                         retworkx.dfs_preorder_nodes(self.dag, self.node_d))
+        self.assertEqual([self.node_d],
+                         retworkx.dfs_postorder_nodes(self.dag, self.node_d))
+
+    def test_dfs_invalid_source(self):
+        for func in (retworkx.dfs_edges, retworkx.dfs_preorder_nodes,
+                     retworkx.dfs_postorder_nodes):
+            self.assertRaises(retworkx.NodeNotFound, func, self.dag, 42)
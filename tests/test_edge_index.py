@@ -0,0 +1,69 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestEdgeIndex(unittest.TestCase):
+
+    def setUp(self):
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', 'edgy')
+        self.edge = self.dag.add_edge(self.node_a, self.node_b, 'super edgy')
+
+    def test_edge_indices(self):
+        self.assertEqual([0, 1], self.dag.edge_indices())
+
+    def test_edge_indices_after_removal(self):
+        self.dag.remove_edge_from_index(0)
+        self.assertEqual([1], self.dag.edge_indices())
+
+    def test_edge_list(self):
+        self.assertEqual([(self.node_a, self.node_b)] * 2,
+                         self.dag.edge_list())
+
+    def test_weighted_edge_list(self):
+        self.assertEqual([(self.node_a, self.node_b, 'edgy'),
+                          (self.node_a, self.node_b, 'super edgy')],
+                         self.dag.weighted_edge_list())
+
+    def test_edge_list_empty(self):
+        dag = retworkx.PyDAG()
+        self.assertEqual([], dag.edge_list())
+        self.assertEqual([], dag.weighted_edge_list())
+
+    def test_get_edge_data_by_index(self):
+        self.assertEqual('super edgy',
+                         self.dag.get_edge_data_by_index(self.edge))
+
+    def test_get_edge_data_by_index_invalid(self):
+        self.assertRaises(IndexError, self.dag.get_edge_data_by_index, 42)
+
+    def test_get_edge_endpoints_by_index(self):
+        self.assertEqual((self.node_a, self.node_b),
+                         self.dag.get_edge_endpoints_by_index(self.edge))
+
+    def test_get_edge_endpoints_by_index_invalid(self):
+        self.assertRaises(IndexError,
+                          self.dag.get_edge_endpoints_by_index, 42)
+
+    def test_update_edge_by_index(self):
+        self.dag.update_edge_by_index(self.edge, 'not edgy')
+        self.assertEqual(['edgy', 'not edgy'], self.dag.edges())
+
+    def test_update_edge_by_index_invalid(self):
+        self.assertRaises(IndexError, self.dag.update_edge_by_index, 42,
+                          'edgy')
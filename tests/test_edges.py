@@ -103,7 +103,8 @@ class TestEdges(unittest.TestCase):
     def test_remove_edge_no_edge(self):
         dag = retworkx.PyDAG()
         node_a = dag.add_node('a')
-        dag.remove_edge_from_index(0)
+        self.assertRaises(retworkx.EdgeNotFound, dag.remove_edge_from_index,
+                          0)
         self.assertEqual([], dag.edges())
 
     def test_add_cycle(self):
@@ -112,3 +113,95 @@ class TestEdges(unittest.TestCase):
         node_b = dag.add_child(node_a, 'b', {})
         self.assertRaises(Exception, dag.add_edge, node_b,
                           node_a, {})
+
+    def test_add_edges_from(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(list(range(4)))
+        res = dag.add_edges_from([(nodes[0], nodes[1], 'a'),
+                                  (nodes[1], nodes[2], 'b'),
+                                  (nodes[0], nodes[3], 'c')])
+        self.assertEqual([0, 1, 2], res)
+        self.assertEqual(['a', 'b', 'c'], dag.edges())
+        self.assertTrue(dag.has_edge(nodes[1], nodes[2]))
+
+    def test_add_edges_from_empty(self):
+        dag = retworkx.PyDAG()
+        self.assertEqual([], dag.add_edges_from([]))
+
+    def test_add_edges_from_no_data(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(list(range(3)))
+        res = dag.add_edges_from_no_data(
+            (nodes[i], nodes[i + 1]) for i in range(2))
+        self.assertEqual([0, 1], res)
+        self.assertEqual([None, None], dag.edges())
+
+    def test_add_edges_from_cycle(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(list(range(3)))
+        self.assertRaises(Exception, dag.add_edges_from,
+                          [(nodes[0], nodes[1], 'a'),
+                           (nodes[1], nodes[2], 'b'),
+                           (nodes[2], nodes[0], 'c')])
+        self.assertEqual(['a', 'b'], dag.edges())
+        self.assertTrue(retworkx.is_directed_acyclic_graph(dag))
+
+    def test_add_edges_from_invalid_node(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(list(range(2)))
+        self.assertRaises(IndexError, dag.add_edges_from,
+                          [(nodes[0], nodes[1], 'a'), (nodes[1], 42, 'b')])
+        self.assertEqual([], dag.edges())
+
+    def test_add_edges_from_atomic(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(list(range(3)))
+        res = dag.add_edges_from([(nodes[0], nodes[1], 'a'),
+                                  (nodes[1], nodes[2], 'b')], atomic=True)
+        self.assertEqual([0, 1], res)
+        self.assertEqual([nodes[0], nodes[1], nodes[2]],
+                         retworkx.topological_sort(dag))
+
+    def test_add_edges_from_atomic_cycle(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(list(range(4)))
+        edge = dag.add_edge(nodes[0], nodes[1], 'a')
+        with self.assertRaises(retworkx.DAGWouldCycle) as cm:
+            dag.add_edges_from([(nodes[1], nodes[2], 'b'),
+                                (nodes[2], nodes[0], 'c'),
+                                (nodes[2], nodes[3], 'd')], atomic=True)
+        self.assertIn('(%s, %s)' % (nodes[2], nodes[0]), str(cm.exception))
+        self.assertEqual(['a'], dag.edges())
+        self.assertEqual([(nodes[0], nodes[1])], dag.edge_list())
+        self.assertTrue(retworkx.is_directed_acyclic_graph(dag))
+        # The edge indices are reused as if the batch was never added
+        self.assertEqual(edge + 1, dag.add_edge(nodes[1], nodes[2], 'b'))
+
+    def test_add_edges_from_no_data_atomic_self_loop(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(list(range(2)))
+        with self.assertRaises(retworkx.DAGWouldCycle) as cm:
+            dag.add_edges_from_no_data([(nodes[0], nodes[1]),
+                                        (nodes[1], nodes[1])], atomic=True)
+        self.assertIn('(%s, %s)' % (nodes[1], nodes[1]), str(cm.exception))
+        self.assertEqual([], dag.edges())
+
+    def test_add_edges_from_atomic_invalid_node(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(list(range(2)))
+        self.assertRaises(retworkx.NodeNotFound, dag.add_edges_from,
+                          [(nodes[0], nodes[1], 'a'), (nodes[1], 42, 'b')],
+                          atomic=True)
+        self.assertEqual([], dag.edges())
+
+    def test_add_edges_from_cycle_without_check(self):
+        graph = retworkx.PyDAG(check_cycle=False)
+        nodes = graph.add_nodes_from(list(range(2)))
+        graph.add_edges_from_no_data([(nodes[0], nodes[1]),
+                                      (nodes[1], nodes[0])])
+        self.assertFalse(retworkx.is_directed_acyclic_graph(graph))
+
+    def test_add_self_loop(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        self.assertRaises(Exception, dag.add_edge, node_a, node_a, {})
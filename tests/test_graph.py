@@ -0,0 +1,154 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import copy
+import pickle
+import unittest
+
+import retworkx
+
+
+class TestGraphNodes(unittest.TestCase):
+
+    def test_nodes(self):
+        graph = retworkx.PyGraph()
+        graph.add_node('a')
+        graph.add_node('b')
+        self.assertEqual(['a', 'b'], graph.nodes())
+
+    def test_remove_node(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        graph.add_edge(node_a, node_b, 'edgy')
+        graph.remove_node(node_a)
+        self.assertEqual(['b'], graph.nodes())
+        self.assertEqual([], graph.edges())
+
+    def test_get_node_data(self):
+        graph = retworkx.PyGraph()
+        graph.add_node('a')
+        node_b = graph.add_node('b')
+        self.assertEqual('b', graph.get_node_data(node_b))
+
+    def test_get_node_data_bad_index(self):
+        graph = retworkx.PyGraph()
+        graph.add_node('a')
+        self.assertRaises(IndexError, graph.get_node_data, 42)
+
+    def test_len(self):
+        graph = retworkx.PyGraph()
+        graph.add_node('a')
+        graph.add_node('b')
+        self.assertEqual(2, len(graph))
+
+
+class TestGraphEdges(unittest.TestCase):
+
+    def test_get_edge_data(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        graph.add_edge(node_a, node_b, 'Edgy')
+        self.assertEqual('Edgy', graph.get_edge_data(node_a, node_b))
+        self.assertEqual('Edgy', graph.get_edge_data(node_b, node_a))
+
+    def test_no_edge(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        self.assertRaises(Exception, graph.get_edge_data, node_a, node_b)
+
+    def test_has_edge(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        graph.add_edge(node_a, node_b, {})
+        self.assertTrue(graph.has_edge(node_a, node_b))
+        self.assertTrue(graph.has_edge(node_b, node_a))
+
+    def test_add_cycle(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        node_c = graph.add_node('c')
+        graph.add_edge(node_a, node_b, 'ab')
+        graph.add_edge(node_b, node_c, 'bc')
+        graph.add_edge(node_c, node_a, 'ca')
+        self.assertEqual(['ab', 'bc', 'ca'], graph.edges())
+
+    def test_remove_edge(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        graph.add_edge(node_a, node_b, 'edgy')
+        graph.remove_edge(node_b, node_a)
+        self.assertEqual([], graph.edges())
+
+    def test_remove_edge_from_index(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        edge = graph.add_edge(node_a, node_b, 'edgy')
+        graph.remove_edge_from_index(edge)
+        self.assertEqual([], graph.edges())
+
+    def test_neighbors(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        node_c = graph.add_node('c')
+        graph.add_edge(node_a, node_b, 'ab')
+        graph.add_edge(node_c, node_a, 'ca')
+        graph.add_edge(node_a, node_b, 'ab2')
+        self.assertEqual(sorted([node_b, node_c]),
+                         sorted(graph.neighbors(node_a)))
+
+    def test_degree(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        node_c = graph.add_node('c')
+        graph.add_edge(node_a, node_b, 'ab')
+        graph.add_edge(node_c, node_a, 'ca')
+        self.assertEqual(2, graph.degree(node_a))
+        self.assertEqual(1, graph.degree(node_b))
+
+    def test_adj(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        node_c = graph.add_node('c')
+        graph.add_edge(node_a, node_b, 'ab')
+        graph.add_edge(node_c, node_a, 'ca')
+        self.assertEqual({node_b: 'ab', node_c: 'ca'}, graph.adj(node_a))
+
+
+class TestGraphPickle(unittest.TestCase):
+
+    def test_pickle_round_trip(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        graph.add_edge(node_a, node_b, 'edgy')
+        res = pickle.loads(pickle.dumps(graph))
+        self.assertEqual(['a', 'b'], res.nodes())
+        self.assertEqual(['edgy'], res.edges())
+
+    def test_deepcopy(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        node_b = graph.add_node('b')
+        graph.add_edge(node_a, node_b, 'edgy')
+        res = copy.deepcopy(graph)
+        self.assertEqual(['a', 'b'], res.nodes())
+        self.assertTrue(res.has_edge(node_b, node_a))
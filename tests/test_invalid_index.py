@@ -0,0 +1,134 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestInvalidNodeIndex(unittest.TestCase):
+
+    def setUp(self):
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', 'edgy')
+        self.stale = self.dag.add_node('c')
+        self.dag.remove_node(self.stale)
+
+    def test_exception_is_index_error(self):
+        self.assertTrue(issubclass(retworkx.NodeNotFound, IndexError))
+        self.assertTrue(issubclass(retworkx.EdgeNotFound, IndexError))
+
+    def test_message_has_index(self):
+        with self.assertRaises(retworkx.NodeNotFound) as cm:
+            self.dag.get_node_data(42)
+        self.assertIn('42', str(cm.exception))
+
+    def test_exception_has_index(self):
+        with self.assertRaises(retworkx.NodeNotFound) as cm:
+            self.dag.remove_node(self.stale)
+        self.assertEqual(self.stale, cm.exception.index)
+
+    def test_remove_node(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.remove_node,
+                          self.stale)
+
+    def test_has_edge(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.has_edge,
+                          self.node_a, self.stale)
+
+    def test_successors(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.successors, 42)
+
+    def test_predecessors(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.predecessors, 42)
+
+    def test_add_child(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.add_child,
+                          self.stale, 'd', None)
+        self.assertEqual(2, len(self.dag))
+
+    def test_add_parent(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.add_parent,
+                          42, 'd', None)
+        self.assertEqual(2, len(self.dag))
+
+    def test_add_edge(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.add_edge,
+                          self.node_a, 42, None)
+
+    def test_add_edges_from(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.add_edges_from,
+                          [(self.node_a, self.node_b, None),
+                           (self.node_b, 42, None)])
+        self.assertEqual(['edgy'], self.dag.edges())
+
+    def test_degree(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.in_degree, 42)
+        self.assertRaises(retworkx.NodeNotFound, self.dag.out_degree, 42)
+
+    def test_in_out_edges(self):
+        self.assertRaises(retworkx.NodeNotFound, self.dag.in_edges, 42)
+        self.assertRaises(retworkx.NodeNotFound, self.dag.out_edges, 42)
+
+    def test_ancestors_descendants(self):
+        self.assertRaises(retworkx.NodeNotFound, retworkx.ancestors,
+                          self.dag, 42)
+        self.assertRaises(retworkx.NodeNotFound, retworkx.descendants,
+                          self.dag, 42)
+
+    def test_bfs_successors(self):
+        self.assertRaises(retworkx.NodeNotFound, retworkx.bfs_successors,
+                          self.dag, 42)
+
+    def test_graph_add_edge(self):
+        graph = retworkx.PyGraph()
+        node_a = graph.add_node('a')
+        self.assertRaises(retworkx.NodeNotFound, graph.add_edge, node_a, 42,
+                          None)
+
+
+class TestInvalidEdgeIndex(unittest.TestCase):
+
+    def test_remove_edge_from_index(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        dag.add_child(node_a, 'b', 'edgy')
+        self.assertRaises(retworkx.EdgeNotFound,
+                          dag.remove_edge_from_index, 42)
+
+    def test_get_edge_data_by_index(self):
+        dag = retworkx.PyDAG()
+        self.assertRaises(retworkx.EdgeNotFound,
+                          dag.get_edge_data_by_index, 0)
+
+    def test_graph_remove_edge_from_index(self):
+        graph = retworkx.PyGraph()
+        self.assertRaises(retworkx.EdgeNotFound,
+                          graph.remove_edge_from_index, 0)
+
+    def test_exception_has_index(self):
+        dag = retworkx.PyDAG()
+        with self.assertRaises(retworkx.EdgeNotFound) as cm:
+            dag.remove_edge_from_index(42)
+        self.assertEqual(42, cm.exception.index)
+        self.assertIn('42', str(cm.exception))
+
+    def test_graph_exception_has_index(self):
+        graph = retworkx.PyGraph()
+        with self.assertRaises(retworkx.NodeNotFound) as cm:
+            graph.remove_node(7)
+        self.assertEqual(7, cm.exception.index)
+        with self.assertRaises(retworkx.EdgeNotFound) as cm:
+            graph.remove_edge_from_index(3)
+        self.assertEqual(3, cm.exception.index)
@@ -0,0 +1,173 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import copy
+import pickle
+import unittest
+
+import retworkx
+
+
+class TestReturnTypes(unittest.TestCase):
+
+    def setUp(self):
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', 'Edgy')
+        self.node_c = self.dag.add_child(self.node_b, 'c', 'Super edgy')
+
+    def test_node_indices(self):
+        res = retworkx.topological_sort(self.dag)
+        self.assertIsInstance(res, retworkx.NodeIndices)
+        self.assertEqual(3, len(res))
+        self.assertEqual(self.node_a, res[0])
+        self.assertEqual(self.node_c, res[-1])
+        self.assertEqual([self.node_a, self.node_b, self.node_c], list(res))
+        self.assertRaises(IndexError, res.__getitem__, 3)
+        self.assertRaises(IndexError, res.__getitem__, -4)
+
+    def test_edge_indices(self):
+        res = self.dag.edge_indices()
+        self.assertIsInstance(res, retworkx.EdgeIndices)
+        self.assertEqual([0, 1], res)
+        self.assertEqual(1, res[-1])
+        self.assertEqual([1], res[1:])
+        self.assertIn(0, res)
+        self.assertEqual([0, 1], list(res))
+
+    def test_edge_list(self):
+        res = self.dag.edge_list()
+        self.assertIsInstance(res, retworkx.EdgeList)
+        self.assertEqual(2, len(res))
+        self.assertEqual((self.node_b, self.node_c), res[1])
+        self.assertEqual([(self.node_a, self.node_b),
+                          (self.node_b, self.node_c)], list(res))
+        self.assertRaises(IndexError, res.__getitem__, 2)
+
+    def test_weighted_edge_list(self):
+        res = self.dag.weighted_edge_list()
+        self.assertIsInstance(res, retworkx.WeightedEdgeList)
+        self.assertEqual(2, len(res))
+        self.assertEqual((self.node_a, self.node_b, 'Edgy'), res[0])
+        self.assertEqual([(self.node_a, self.node_b, 'Edgy'),
+                          (self.node_b, self.node_c, 'Super edgy')],
+                         list(res))
+        self.assertIsInstance(self.dag.in_edges(self.node_b),
+                              retworkx.WeightedEdgeList)
+        self.assertIsInstance(self.dag.out_edges(self.node_b),
+                              retworkx.WeightedEdgeList)
+
+    def test_eq(self):
+        res = retworkx.topological_sort(self.dag)
+        self.assertTrue(res == [self.node_a, self.node_b, self.node_c])
+        self.assertTrue([self.node_a, self.node_b, self.node_c] == res)
+        self.assertTrue(res == (self.node_a, self.node_b, self.node_c))
+        self.assertTrue(res == retworkx.topological_sort(self.dag))
+        self.assertFalse(res == [self.node_a, self.node_b])
+        self.assertFalse(res == [self.node_c, self.node_b, self.node_a])
+        self.assertFalse(res == ['a', 'b', 'c'])
+        self.assertFalse(res == 42)
+
+    def test_ne(self):
+        res = self.dag.weighted_edge_list()
+        self.assertFalse(res != self.dag.weighted_edge_list())
+        self.assertTrue(res != [(self.node_a, self.node_b, 'Edgy')])
+        self.assertTrue(res != [(self.node_a, self.node_b, 'Edgy'),
+                                (self.node_b, self.node_c, 'Not edgy')])
+
+    def test_empty(self):
+        dag = retworkx.PyDAG()
+        self.assertEqual([], retworkx.topological_sort(dag))
+        self.assertEqual([], dag.edge_list())
+        self.assertEqual([], dag.weighted_edge_list())
+        self.assertEqual(0, len(dag.edge_list()))
+
+    def test_pickle(self):
+        for res in (retworkx.topological_sort(self.dag),
+                    self.dag.edge_indices(), self.dag.edge_list(),
+                    self.dag.weighted_edge_list()):
+            with self.subTest(type=type(res).__name__):
+                unpickled = pickle.loads(pickle.dumps(res))
+                self.assertIsInstance(unpickled, type(res))
+                self.assertEqual(list(res), list(unpickled))
+
+    def test_deepcopy(self):
+        res = self.dag.weighted_edge_list()
+        res_copy = copy.deepcopy(res)
+        self.assertEqual(res, res_copy)
+
+    def test_slice(self):
+        res = retworkx.topological_sort(self.dag)
+        self.assertIsInstance(res[1:], retworkx.NodeIndices)
+        self.assertEqual([self.node_b, self.node_c], res[1:])
+        self.assertEqual([self.node_c, self.node_a], res[::-2])
+        self.assertEqual([], res[5:])
+        edges = self.dag.weighted_edge_list()
+        self.assertIsInstance(edges[:1], retworkx.WeightedEdgeList)
+        self.assertEqual([(self.node_a, self.node_b, 'Edgy')], edges[:1])
+        self.assertEqual([(self.node_b, self.node_c)],
+                         self.dag.edge_list()[-1:])
+        self.assertRaises(TypeError, res.__getitem__, 'a')
+
+    def test_index_and_count(self):
+        res = retworkx.topological_sort(self.dag)
+        self.assertEqual(1, res.index(self.node_b))
+        self.assertRaises(ValueError, res.index, self.node_a, 1)
+        self.assertRaises(ValueError, res.index, self.node_c, 0, -1)
+        self.assertRaises(ValueError, res.index, 42)
+        self.assertEqual(1, res.count(self.node_a))
+        self.assertEqual(0, res.count(42))
+        edges = self.dag.edge_list()
+        self.assertEqual(1, edges.index((self.node_b, self.node_c)))
+        self.assertEqual(1, edges.count((self.node_a, self.node_b)))
+        weighted = self.dag.weighted_edge_list()
+        self.assertEqual(
+            1, weighted.index((self.node_b, self.node_c, 'Super edgy')))
+
+    def test_contains(self):
+        res = retworkx.topological_sort(self.dag)
+        self.assertIn(self.node_a, res)
+        self.assertNotIn(42, res)
+        self.assertNotIn('a', res)
+        self.assertIn((self.node_a, self.node_b), self.dag.edge_list())
+        self.assertNotIn((self.node_b, self.node_a), self.dag.edge_list())
+        self.assertIn((self.node_a, self.node_b, 'Edgy'),
+                      self.dag.weighted_edge_list())
+
+    def test_ordering(self):
+        res = retworkx.topological_sort(self.dag)
+        as_list = [self.node_a, self.node_b, self.node_c]
+        for other in (as_list, as_list[:2], as_list + [42], [], [100],
+                      [self.node_a, self.node_c]):
+            with self.subTest(other=other):
+                self.assertEqual(as_list < other, res < other)
+                self.assertEqual(as_list <= other, res <= other)
+                self.assertEqual(as_list > other, res > other)
+                self.assertEqual(as_list >= other, res >= other)
+                self.assertEqual(other < as_list, other < res)
+        edges = self.dag.edge_list()
+        self.assertLess(edges, [(self.node_b, self.node_a)])
+        self.assertGreater(edges, list(edges)[:1])
+
+    def test_ordering_not_a_sequence(self):
+        res = retworkx.topological_sort(self.dag)
+        self.assertRaises(TypeError, lambda: res < 42)
+        self.assertRaises(TypeError, lambda: res >= None)
+        self.assertTrue(res != 42)
+
+    def test_compare_sequence_types(self):
+        res = retworkx.topological_sort(self.dag)
+        self.assertEqual(res, retworkx.topological_sort(self.dag))
+        self.assertEqual(self.dag.edge_indices(), res[:2])
+        self.assertLess(self.dag.edge_indices(), res)
+        self.assertNotEqual(res, self.dag.edge_list())
@@ -0,0 +1,103 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestLayers(unittest.TestCase):
+
+    def setUp(self):
+        """
+        a   b
+        |\\ /
+        c d
+        |/
+        e
+        """
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_node('b')
+        self.node_c = self.dag.add_child(self.node_a, 'c', {})
+        self.node_d = self.dag.add_child(self.node_a, 'd', {})
+        self.dag.add_edge(self.node_b, self.node_d, {})
+        self.node_e = self.dag.add_child(self.node_c, 'e', {})
+        self.dag.add_edge(self.node_d, self.node_e, {})
+
+    def test_topological_generations(self):
+        self.assertEqual(
+            [[self.node_a, self.node_b], [self.node_c, self.node_d],
+             [self.node_e]],
+            retworkx.topological_generations(self.dag))
+
+    def test_layers(self):
+        self.assertEqual(
+            [[self.node_a, self.node_b], [self.node_c, self.node_d],
+             [self.node_e]],
+            retworkx.layers(self.dag, [self.node_a, self.node_b]))
+
+    def test_layers_partial_first_layer(self):
+        # d has b as a predecessor which is never reached, so neither d nor
+        # e are part of any layer.
+        self.assertEqual([[self.node_a], [self.node_c]],
+                         retworkx.layers(self.dag, [self.node_a]))
+
+    def test_layers_repeated_node(self):
+        # d also depends on b, repeating a must not count as reaching d twice
+        self.assertEqual([[self.node_a], [self.node_c]],
+                         retworkx.layers(self.dag, [self.node_a, self.node_a]))
+        self.assertEqual(
+            [[self.node_a, self.node_b], [self.node_c, self.node_d],
+             [self.node_e]],
+            retworkx.layers(self.dag,
+                            [self.node_a, self.node_b, self.node_a]))
+
+    def test_layers_descendant_in_first_layer(self):
+        # d is a descendant of a, it stays in the first layer
+        self.assertEqual(
+            [[self.node_a, self.node_d], [self.node_c], [self.node_e]],
+            retworkx.layers(self.dag, [self.node_a, self.node_d]))
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', {})
+        self.assertEqual([[node_a, node_b]],
+                         retworkx.layers(dag, [node_a, node_b]))
+
+    def test_parallel_edges(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', {})
+        dag.add_edge(node_a, node_b, {})
+        self.assertEqual([[node_a], [node_b]],
+                         retworkx.topological_generations(dag))
+
+    def test_empty(self):
+        dag = retworkx.PyDAG()
+        self.assertEqual([], retworkx.topological_generations(dag))
+        self.assertEqual([], retworkx.layers(dag, []))
+
+    def test_cycle(self):
+        dag = retworkx.PyDAG(check_cycle=False)
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', {})
+        node_c = dag.add_child(node_b, 'c', {})
+        dag.add_edge(node_c, node_b, {})
+        self.assertRaises(retworkx.DAGHasCycle,
+                          retworkx.topological_generations, dag)
+        self.assertRaises(retworkx.DAGHasCycle, retworkx.layers, dag,
+                          [node_a])
+
+    def test_layers_invalid_node(self):
+        self.assertRaises(retworkx.NodeNotFound, retworkx.layers, self.dag,
+                          [42])
@@ -0,0 +1,108 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestLowestCommonAncestors(unittest.TestCase):
+
+    def setUp(self):
+        """
+        a   b   f
+        |\\ /|
+        | X |
+        |/ \\|
+        c   d
+        |
+        e
+        """
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_node('b')
+        self.node_c = self.dag.add_child(self.node_a, 'c', {})
+        self.node_d = self.dag.add_child(self.node_a, 'd', {})
+        self.dag.add_edge(self.node_b, self.node_c, {})
+        self.dag.add_edge(self.node_b, self.node_d, {})
+        self.node_e = self.dag.add_child(self.node_c, 'e', {})
+        self.node_f = self.dag.add_node('f')
+
+    def test_multiple_lcas(self):
+        self.assertEqual(
+            [self.node_a, self.node_b],
+            retworkx.lowest_common_ancestors(self.dag, self.node_c,
+                                             self.node_d))
+
+    def test_ancestor_is_lca(self):
+        self.assertEqual(
+            [self.node_c],
+            retworkx.lowest_common_ancestors(self.dag, self.node_c,
+                                             self.node_e))
+        self.assertEqual(
+            [self.node_e],
+            retworkx.lowest_common_ancestors(self.dag, self.node_e,
+                                             self.node_e))
+
+    def test_no_common_ancestor(self):
+        self.assertEqual(
+            [], retworkx.lowest_common_ancestors(self.dag, self.node_a,
+                                                 self.node_f))
+
+    def test_all_pairs(self):
+        res = retworkx.all_pairs_lowest_common_ancestors(self.dag)
+        self.assertEqual([self.node_a, self.node_b],
+                         res[(self.node_c, self.node_d)])
+        self.assertEqual([self.node_a, self.node_b],
+                         res[(self.node_d, self.node_e)])
+        self.assertEqual([self.node_f], res[(self.node_f, self.node_f)])
+        self.assertNotIn((self.node_a, self.node_f), res)
+        for (node_a, node_b), lcas in res.items():
+            self.assertEqual(
+                retworkx.lowest_common_ancestors(self.dag, node_a, node_b),
+                lcas)
+
+    def test_all_pairs_subset(self):
+        res = retworkx.all_pairs_lowest_common_ancestors(
+            self.dag, [(self.node_e, self.node_c), (self.node_a,
+                                                    self.node_b)])
+        self.assertEqual({(self.node_e, self.node_c): [self.node_c]}, res)
+
+    def test_all_pairs_shared_nodes(self):
+        pairs = [(self.node_c, self.node_d), (self.node_c, self.node_e),
+                 (self.node_d, self.node_c)]
+        res = retworkx.all_pairs_lowest_common_ancestors(self.dag, pairs)
+        self.assertEqual(set(pairs), set(res))
+        for node_a, node_b in pairs:
+            self.assertEqual(
+                retworkx.lowest_common_ancestors(self.dag, node_a, node_b),
+                res[(node_a, node_b)])
+
+    def test_invalid_node(self):
+        self.assertRaises(retworkx.NodeNotFound,
+                          retworkx.lowest_common_ancestors, self.dag,
+                          self.node_a, 42)
+        self.assertRaises(retworkx.NodeNotFound,
+                          retworkx.all_pairs_lowest_common_ancestors,
+                          self.dag, [(self.node_a, 42)])
+
+    def test_cycle(self):
+        dag = retworkx.PyDAG(check_cycle=False)
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', {})
+        dag.add_edge(node_b, node_a, {})
+        self.assertRaises(retworkx.DAGHasCycle,
+                          retworkx.lowest_common_ancestors, dag, node_a,
+                          node_b)
+        self.assertRaises(retworkx.DAGHasCycle,
+                          retworkx.all_pairs_lowest_common_ancestors, dag)
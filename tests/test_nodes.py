@@ -39,7 +39,7 @@ class TestNodes(unittest.TestCase):
             dag.add_child(node_a, i, None)
         dag.add_parent(3, 'A parent', None)
         res = retworkx.topological_sort(dag)
-        self.assertEqual([6, 0, 5, 4, 3, 2, 1], res)
+        self.assertEqual([6, 0, 1, 2, 3, 4, 5], res)
 
     def test_lexicographical_topo_sort(self):
         dag = retworkx.PyDAG()
@@ -48,10 +48,58 @@ class TestNodes(unittest.TestCase):
             dag.add_child(node_a, i, None)
         dag.add_parent(3, 'A parent', None)
         res = retworkx.lexicographical_topological_sort(dag, lambda x: str(x))
-        # Node values for nodes [6, 0, 5, 4, 3, 2, 1]
-        expected = ['A parent', 'a', 4, 3, 2, 1, 0]
+        # Node values for nodes [6, 0, 1, 2, 3, 4, 5]
+        expected = ['A parent', 'a', 0, 1, 2, 3, 4]
         self.assertEqual(expected, res)
 
+    def test_lexicographical_topo_sort_reverse(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        for i in range(5):
+            dag.add_child(node_a, i, None)
+        dag.add_parent(3, 'A parent', None)
+        res = retworkx.lexicographical_topological_sort(dag, lambda x: str(x),
+                                                        reverse=True)
+        # Node values for nodes [1, 2, 3, 4, 5, 6, 0]
+        expected = [0, 1, 2, 3, 4, 'A parent', 'a']
+        self.assertEqual(expected, res)
+
+    def test_lexicographical_topo_sort_int_key(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from([10, 2, 33, 1])
+        dag.add_edge(nodes[2], nodes[3], None)
+        res = retworkx.lexicographical_topological_sort(dag, lambda x: x)
+        self.assertEqual([2, 10, 33, 1], res)
+
+    def test_lexicographical_topo_sort_tuple_key(self):
+        dag = retworkx.PyDAG()
+        dag.add_nodes_from([(1, 'b'), (0, 'z'), (1, 'a')])
+        res = retworkx.lexicographical_topological_sort(dag, lambda x: x)
+        self.assertEqual([(0, 'z'), (1, 'a'), (1, 'b')], res)
+
+    def test_lexicographical_topo_sort_equal_keys(self):
+        dag = retworkx.PyDAG()
+        dag.add_nodes_from(['c', 'b', 'a'])
+        res = retworkx.lexicographical_topological_sort(dag, lambda x: 0)
+        # Ties are broken by picking the highest node index first
+        self.assertEqual(['a', 'b', 'c'], res)
+
+    def test_lexicographical_topo_sort_incomparable_keys(self):
+        dag = retworkx.PyDAG()
+        dag.add_nodes_from([1, 'a'])
+        self.assertRaises(TypeError, retworkx.lexicographical_topological_sort,
+                          dag, lambda x: x)
+
+    def test_lexicographical_topo_sort_cycle(self):
+        dag = retworkx.PyDAG(check_cycle=False)
+        nodes = dag.add_nodes_from(['a', 'b', 'c'])
+        dag.add_edge(nodes[0], nodes[1], None)
+        dag.add_edge(nodes[1], nodes[2], None)
+        dag.add_edge(nodes[2], nodes[1], None)
+        self.assertRaises(retworkx.DAGHasCycle,
+                          retworkx.lexicographical_topological_sort, dag,
+                          lambda x: x)
+
     def test_get_node_data(self):
         dag = retworkx.PyDAG()
         node_a = dag.add_node('a')
@@ -73,3 +121,17 @@ class TestNodes(unittest.TestCase):
     def test_pydag_length_empty(self):
         dag = retworkx.PyDAG()
         self.assertEqual(0, len(dag))
+
+    def test_add_nodes_from(self):
+        dag = retworkx.PyDAG()
+        nodes = list(range(100))
+        res = dag.add_nodes_from(nodes)
+        self.assertEqual(len(res), 100)
+        self.assertEqual(res, nodes)
+        self.assertEqual(nodes, dag.nodes())
+
+    def test_add_nodes_from_generator(self):
+        dag = retworkx.PyDAG()
+        res = dag.add_nodes_from(str(x) for x in range(3))
+        self.assertEqual([0, 1, 2], res)
+        self.assertEqual(['0', '1', '2'], dag.nodes())
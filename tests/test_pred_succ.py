@@ -123,3 +123,71 @@ class TestBfsSuccessors(unittest.TestCase):
         self.assertEqual(expected, res)
         self.assertEqual([(7, [8]), (8, [9]), (9, [10])],
                          retworkx.bfs_successors(dag, node_h))
+
+    def test_bfs_successors_depth_limit(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', {})
+        node_c = dag.add_child(node_b, 'c', {})
+        dag.add_child(node_c, 'd', {})
+        self.assertEqual([], retworkx.bfs_successors(dag, node_a, 0))
+        self.assertEqual([('a', ['b'])],
+                         retworkx.bfs_successors(dag, node_a, 1))
+        self.assertEqual([('a', ['b']), ('b', ['c'])],
+                         retworkx.bfs_successors(dag, node_a,
+                                                 depth_limit=2))
+
+    def test_bfs_successor_indices(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', {})
+        node_c = dag.add_child(node_b, 'c', {})
+        node_d = dag.add_child(node_c, 'd', {})
+        self.assertEqual([(node_b, [node_c]), (node_c, [node_d])],
+                         retworkx.bfs_successor_indices(dag, node_b))
+        self.assertEqual([(node_b, [node_c])],
+                         retworkx.bfs_successor_indices(dag, node_b, 1))
+
+
+class TestBfsPredecessors(unittest.TestCase):
+    def setUp(self):
+        """
+        a   b
+         \ /
+          c
+          |
+          d
+        """
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_node('b')
+        self.node_c = self.dag.add_child(self.node_a, 'c', {})
+        self.dag.add_edge(self.node_b, self.node_c, {})
+        self.node_d = self.dag.add_child(self.node_c, 'd', {})
+
+    def test_bfs_predecessors(self):
+        res = retworkx.bfs_predecessors(self.dag, self.node_d)
+        self.assertEqual(2, len(res))
+        self.assertEqual(('d', ['c']), res[0])
+        self.assertEqual('c', res[1][0])
+        self.assertEqual(['a', 'b'], sorted(res[1][1]))
+
+    def test_bfs_predecessors_depth_limit(self):
+        self.assertEqual([('d', ['c'])],
+                         retworkx.bfs_predecessors(self.dag, self.node_d, 1))
+
+    def test_bfs_predecessor_indices(self):
+        res = retworkx.bfs_predecessor_indices(self.dag, self.node_d)
+        self.assertEqual((self.node_d, [self.node_c]), res[0])
+        self.assertEqual(self.node_c, res[1][0])
+        self.assertEqual([self.node_a, self.node_b], sorted(res[1][1]))
+
+    def test_bfs_predecessors_no_predecessors(self):
+        self.assertEqual([], retworkx.bfs_predecessors(self.dag, self.node_a))
+
+    def test_bfs_predecessors_invalid_node(self):
+        self.assertRaises(retworkx.NodeNotFound, retworkx.bfs_predecessors,
+                          self.dag, 42)
+        self.assertRaises(retworkx.NodeNotFound,
+                          retworkx.bfs_predecessor_indices, self.dag, 42)
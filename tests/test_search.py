@@ -0,0 +1,210 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class RecordingVisitor:
+
+    def __init__(self):
+        self.events = []
+
+    def discover_vertex(self, node):
+        self.events.append(('discover', node))
+
+    def tree_edge(self, edge):
+        self.events.append(('tree', edge[0], edge[1]))
+
+    def non_tree_edge(self, edge):
+        self.events.append(('non_tree', edge[0], edge[1]))
+
+    def finish_vertex(self, node):
+        self.events.append(('finish', node))
+
+
+class TestSearch(unittest.TestCase):
+
+    def setUp(self):
+        """
+          a
+         / \\
+        b   c
+         \\ /
+          d
+        """
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', 'ab')
+        self.node_c = self.dag.add_child(self.node_a, 'c', 'ac')
+        self.node_d = self.dag.add_child(self.node_b, 'd', 'bd')
+        self.dag.add_edge(self.node_c, self.node_d, 'cd')
+
+    def test_bfs_search(self):
+        visitor = RecordingVisitor()
+        retworkx.bfs_search(self.dag, [self.node_a], visitor)
+        events = visitor.events
+        self.assertEqual(('discover', self.node_a), events[0])
+        self.assertEqual(('finish', self.node_d), events[-1])
+        discovered = [e[1] for e in events if e[0] == 'discover']
+        self.assertEqual(4, len(discovered))
+        self.assertEqual(self.node_d, discovered[-1])
+        self.assertEqual(3, len([e for e in events if e[0] == 'tree']))
+        non_tree = [e for e in events if e[0] == 'non_tree']
+        self.assertEqual(1, len(non_tree))
+        self.assertEqual(self.node_d, non_tree[0][2])
+        # Both children of a are discovered before a is finished
+        self.assertLess(events.index(('discover', self.node_b)),
+                        events.index(('finish', self.node_a)))
+        self.assertLess(events.index(('discover', self.node_c)),
+                        events.index(('finish', self.node_a)))
+
+    def test_dfs_search(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', None)
+        node_c = dag.add_child(node_b, 'c', None)
+        dag.add_edge(node_a, node_c, None)
+        visitor = RecordingVisitor()
+        retworkx.dfs_search(dag, [node_a], visitor)
+        self.assertEqual(('discover', node_a), visitor.events[0])
+        self.assertEqual(('finish', node_a), visitor.events[-1])
+        self.assertEqual(
+            [node_c, node_b, node_a],
+            [e[1] for e in visitor.events if e[0] == 'finish'])
+        self.assertEqual(1, len([e for e in visitor.events
+                                 if e[0] == 'non_tree']))
+
+    def test_search_all_nodes(self):
+        node_e = self.dag.add_node('e')
+        for search in (retworkx.bfs_search, retworkx.dfs_search):
+            visitor = RecordingVisitor()
+            search(self.dag, None, visitor)
+            discovered = [e[1] for e in visitor.events if e[0] == 'discover']
+            self.assertEqual(
+                sorted([self.node_a, self.node_b, self.node_c, self.node_d,
+                        node_e]), sorted(discovered))
+
+    def test_prune_tree_edge(self):
+        class PruneVisitor(RecordingVisitor):
+            def tree_edge(self, edge):
+                if edge[1] == 1:
+                    raise retworkx.PruneSearch
+                super().tree_edge(edge)
+
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', None)
+        dag.add_child(node_b, 'c', None)
+        for search in (retworkx.bfs_search, retworkx.dfs_search):
+            visitor = PruneVisitor()
+            search(dag, [node_a], visitor)
+            self.assertEqual([('discover', node_a), ('finish', node_a)],
+                             visitor.events)
+
+    def test_prune_discover_vertex(self):
+        class PruneVisitor(RecordingVisitor):
+            def discover_vertex(self, node):
+                super().discover_vertex(node)
+                if node == 1:
+                    raise retworkx.PruneSearch
+
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', None)
+        node_c = dag.add_child(node_b, 'c', None)
+        for search in (retworkx.bfs_search, retworkx.dfs_search):
+            visitor = PruneVisitor()
+            search(dag, [node_a], visitor)
+            self.assertIn(('finish', node_b), visitor.events)
+            self.assertNotIn(('discover', node_c), visitor.events)
+
+    def test_stop_search(self):
+        class StopVisitor(RecordingVisitor):
+            def discover_vertex(self, node):
+                super().discover_vertex(node)
+                if node == 1:
+                    raise retworkx.StopSearch
+
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', None)
+        dag.add_child(node_b, 'c', None)
+        for search in (retworkx.bfs_search, retworkx.dfs_search):
+            visitor = StopVisitor()
+            self.assertIsNone(search(dag, [node_a], visitor))
+            self.assertEqual(('discover', node_b), visitor.events[-1])
+            self.assertNotIn(('finish', node_a), visitor.events)
+
+    def test_partial_visitor(self):
+        class DiscoverVisitor:
+            def __init__(self):
+                self.discovered = []
+
+            def discover_vertex(self, node):
+                self.discovered.append(node)
+
+        for search in (retworkx.bfs_search, retworkx.dfs_search):
+            visitor = DiscoverVisitor()
+            search(self.dag, [self.node_d], visitor)
+            self.assertEqual([self.node_d], visitor.discovered)
+
+    def test_exception_propagates(self):
+        class BadVisitor:
+            def discover_vertex(self, node):
+                raise KeyError(node)
+
+        for search in (retworkx.bfs_search, retworkx.dfs_search):
+            self.assertRaises(KeyError, search, self.dag, [self.node_a],
+                              BadVisitor())
+
+    def test_invalid_source(self):
+        for search in (retworkx.bfs_search, retworkx.dfs_search):
+            self.assertRaises(retworkx.NodeNotFound, search, self.dag, [42],
+                              RecordingVisitor())
+
+    def test_visitor_mutates_graph(self):
+        class MutatingVisitor(RecordingVisitor):
+            def __init__(self, dag):
+                super().__init__()
+                self.dag = dag
+                self.weights = []
+
+            def discover_vertex(self, node):
+                super().discover_vertex(node)
+                self.dag.add_node('new')
+
+            def tree_edge(self, edge):
+                super().tree_edge(edge)
+                self.weights.append(edge[2])
+                if edge[0] in self.dag:
+                    self.dag.remove_node(edge[0])
+
+        for search in (retworkx.bfs_search, retworkx.dfs_search):
+            dag = retworkx.PyDAG()
+            node_a = dag.add_node('a')
+            node_b = dag.add_child(node_a, 'b', 'ab')
+            node_c = dag.add_child(node_a, 'c', 'ac')
+            node_d = dag.add_child(node_b, 'd', 'bd')
+            visitor = MutatingVisitor(dag)
+            search(dag, [node_a], visitor)
+            # The edges of a node are followed as they were when the node
+            # was reached, even after the visitor removed it.
+            self.assertEqual(['ab', 'ac', 'bd'], sorted(visitor.weights))
+            self.assertEqual(
+                [node_a, node_b, node_c, node_d],
+                sorted(e[1] for e in visitor.events if e[0] == 'discover'))
+            # The indices of the removed nodes are reused by the new nodes
+            self.assertNotIn('a', dag.nodes())
+            self.assertNotIn('b', dag.nodes())
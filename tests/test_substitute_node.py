@@ -0,0 +1,86 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestSubstituteNodeWithSubgraph(unittest.TestCase):
+
+    def setUp(self):
+        # a -> b -> c
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', 'ab')
+        self.node_c = self.dag.add_child(self.node_b, 'c', 'bc')
+        # x -> y -> z
+        self.other = retworkx.PyDAG()
+        self.node_x = self.other.add_node('x')
+        self.node_y = self.other.add_child(self.node_x, 'y', 'xy')
+        self.node_z = self.other.add_child(self.node_y, 'z', 'yz')
+
+    def edge_map_fn(self, source, target, _weight):
+        if source == self.node_b:
+            return self.node_z
+        return self.node_x
+
+    def test_substitute(self):
+        res = self.dag.substitute_node_with_subgraph(
+            self.node_b, self.other, self.edge_map_fn)
+        self.assertEqual({self.node_x, self.node_y, self.node_z},
+                         set(res.keys()))
+        self.assertNotIn(self.node_b, self.dag.nodes())
+        self.assertEqual(['a', 'c', 'x', 'y', 'z'],
+                         sorted(self.dag.nodes()))
+        self.assertEqual(
+            ['a', 'x', 'y', 'z', 'c'],
+            [self.dag[n] for n in retworkx.topological_sort(self.dag)])
+        self.assertEqual('ab', self.dag.get_edge_data(self.node_a,
+                                                      res[self.node_x]))
+        self.assertEqual('bc', self.dag.get_edge_data(res[self.node_z],
+                                                      self.node_c))
+        self.assertEqual('xy', self.dag.get_edge_data(res[self.node_x],
+                                                      res[self.node_y]))
+
+    def test_substitute_drop_edge(self):
+        def edge_map_fn(source, target, _weight):
+            if source == self.node_b:
+                return None
+            return self.node_x
+
+        res = self.dag.substitute_node_with_subgraph(
+            self.node_b, self.other, edge_map_fn)
+        self.assertEqual([], self.dag.in_edges(self.node_c))
+        self.assertEqual(3, len(self.dag.edges()))
+        self.assertTrue(self.dag.has_edge(self.node_a, res[self.node_x]))
+
+    def test_substitute_invalid_mapping(self):
+        self.assertRaises(retworkx.NodeNotFound,
+                          self.dag.substitute_node_with_subgraph,
+                          self.node_b, self.other, lambda *args: 42)
+        self.assertEqual(['a', 'b', 'c'], self.dag.nodes())
+        self.assertEqual(['ab', 'bc'], self.dag.edges())
+
+    def test_substitute_cyclic_other(self):
+        self.other.check_cycle = False
+        self.other.add_edge(self.node_z, self.node_x, 'zx')
+        self.assertRaises(retworkx.DAGWouldCycle,
+                          self.dag.substitute_node_with_subgraph,
+                          self.node_b, self.other, self.edge_map_fn)
+        self.assertEqual(['a', 'b', 'c'], self.dag.nodes())
+
+    def test_substitute_invalid_node(self):
+        self.assertRaises(retworkx.NodeNotFound,
+                          self.dag.substitute_node_with_subgraph,
+                          42, self.other, self.edge_map_fn)
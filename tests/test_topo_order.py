@@ -0,0 +1,109 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import pickle
+import random
+import unittest
+
+import retworkx
+
+
+class TestTopologicalOrder(unittest.TestCase):
+
+    def assertTopologicalOrder(self, dag, order):
+        self.assertEqual(sorted(dag), sorted(order))
+        position = {node: i for i, node in enumerate(order)}
+        for source, target in dag.edge_list():
+            self.assertLess(position[source], position[target])
+
+    def test_backward_edges(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(range(5))
+        dag.add_edge(nodes[4], nodes[3], None)
+        dag.add_edge(nodes[3], nodes[0], None)
+        dag.add_edge(nodes[1], nodes[4], None)
+        res = retworkx.topological_sort(dag)
+        self.assertEqual([nodes[1], nodes[4], nodes[2], nodes[3], nodes[0]],
+                         res)
+        self.assertTopologicalOrder(dag, res)
+        self.assertRaises(retworkx.DAGWouldCycle, dag.add_edge, nodes[0],
+                          nodes[1], None)
+
+    def test_cycle_after_reorder(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(range(4))
+        dag.add_edge(nodes[3], nodes[2], None)
+        dag.add_edge(nodes[2], nodes[1], None)
+        dag.add_edge(nodes[1], nodes[0], None)
+        self.assertRaises(retworkx.DAGWouldCycle, dag.add_edge, nodes[0],
+                          nodes[3], None)
+        self.assertRaises(retworkx.DAGWouldCycle, dag.add_edge, nodes[1],
+                          nodes[1], None)
+        dag.add_edge(nodes[3], nodes[0], None)
+        self.assertEqual([nodes[3], nodes[2], nodes[1], nodes[0]],
+                         retworkx.topological_sort(dag))
+
+    def test_random_dag(self):
+        rng = random.Random(42)
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(range(50))
+        # Edges from a lower to a higher value are inserted in a random
+        # order, an edge in the other direction is only rejected if there is
+        # already a path back to its source.
+        for _ in range(300):
+            source, target = rng.sample(nodes, 2)
+            if source < target:
+                dag.add_edge(source, target, None)
+            elif retworkx.has_path(dag, target, source):
+                self.assertRaises(retworkx.DAGWouldCycle, dag.add_edge,
+                                  source, target, None)
+        self.assertTopologicalOrder(dag, retworkx.topological_sort(dag))
+
+    def test_remove_node(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', None)
+        node_c = dag.add_parent(node_a, 'c', None)
+        dag.remove_node(node_a)
+        node_d = dag.add_parent(node_b, 'd', None)
+        self.assertEqual({node_b, node_c, node_d}, set(dag))
+        res = retworkx.topological_sort(dag)
+        self.assertEqual([node_d, node_c, node_b], res)
+        self.assertTopologicalOrder(dag, res)
+        self.assertRaises(retworkx.DAGWouldCycle, dag.add_edge, node_b,
+                          node_d, None)
+
+    def test_check_cycle_disabled(self):
+        dag = retworkx.PyDAG(check_cycle=False)
+        node_a = dag.add_node('a')
+        node_b = dag.add_node('b')
+        dag.add_edge(node_b, node_a, None)
+        self.assertEqual([node_b, node_a], retworkx.topological_sort(dag))
+        dag.add_edge(node_a, node_b, None)
+        self.assertRaises(retworkx.DAGHasCycle, retworkx.topological_sort,
+                          dag)
+        dag.remove_edge(node_a, node_b)
+        self.assertEqual([node_b, node_a], retworkx.topological_sort(dag))
+        dag.check_cycle = True
+        self.assertRaises(retworkx.DAGWouldCycle, dag.add_edge, node_a,
+                          node_b, None)
+
+    def test_pickle(self):
+        dag = retworkx.PyDAG()
+        nodes = dag.add_nodes_from(range(3))
+        dag.add_edge(nodes[2], nodes[0], None)
+        dag.add_edge(nodes[1], nodes[2], None)
+        new_dag = pickle.loads(pickle.dumps(dag))
+        self.assertTopologicalOrder(new_dag,
+                                    retworkx.topological_sort(new_dag))
+        self.assertRaises(retworkx.DAGWouldCycle, new_dag.add_edge,
+                          nodes[0], nodes[1], None)
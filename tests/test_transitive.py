@@ -0,0 +1,105 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestTransitive(unittest.TestCase):
+
+    def setUp(self):
+        # a -> b -> c -> d with the redundant edges a -> c and a -> d
+        super().setUp()
+        self.dag = retworkx.PyDAG()
+        self.node_a = self.dag.add_node('a')
+        self.node_b = self.dag.add_child(self.node_a, 'b', 'ab')
+        self.node_c = self.dag.add_child(self.node_b, 'c', 'bc')
+        self.node_d = self.dag.add_child(self.node_c, 'd', 'cd')
+        self.dag.add_edge(self.node_a, self.node_c, 'ac')
+        self.dag.add_edge(self.node_a, self.node_d, 'ad')
+
+    def test_transitive_reduction(self):
+        res, node_map = retworkx.transitive_reduction(self.dag)
+        self.assertEqual(['a', 'b', 'c', 'd'], res.nodes())
+        self.assertEqual({self.node_a, self.node_b, self.node_c,
+                          self.node_d}, set(node_map.keys()))
+        self.assertEqual(
+            [(node_map[self.node_a], node_map[self.node_b], 'ab'),
+             (node_map[self.node_b], node_map[self.node_c], 'bc'),
+             (node_map[self.node_c], node_map[self.node_d], 'cd')],
+            sorted(res.weighted_edge_list()))
+        # The input is not modified
+        self.assertEqual(5, len(self.dag.edge_list()))
+
+    def test_transitive_reduction_parallel_edges(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', 'first')
+        dag.add_edge(node_a, node_b, 'second')
+        res, node_map = retworkx.transitive_reduction(dag)
+        self.assertEqual(1, len(res.edge_list()))
+
+    def test_transitive_closure(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', 'ab')
+        node_c = dag.add_child(node_b, 'c', 'bc')
+        node_d = dag.add_node('d')
+        res, node_map = retworkx.transitive_closure(dag)
+        self.assertEqual(['a', 'b', 'c', 'd'], res.nodes())
+        self.assertEqual(
+            [(node_map[node_a], node_map[node_b], 'ab'),
+             (node_map[node_a], node_map[node_c], None),
+             (node_map[node_b], node_map[node_c], 'bc')],
+            sorted(res.weighted_edge_list(), key=lambda x: x[:2]))
+        self.assertEqual({}, res.adj(node_map[node_d]))
+
+    def test_transitive_closure_parallel_edges(self):
+        dag = retworkx.PyDAG()
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', 'first')
+        dag.add_edge(node_a, node_b, 'second')
+        node_c = dag.add_child(node_b, 'c', 'bc')
+        res, node_map = retworkx.transitive_closure(dag)
+        self.assertEqual(
+            [(node_map[node_a], node_map[node_b], 'first'),
+             (node_map[node_a], node_map[node_b], 'second'),
+             (node_map[node_a], node_map[node_c], None),
+             (node_map[node_b], node_map[node_c], 'bc')],
+            sorted(res.weighted_edge_list(), key=lambda x: x[:2]))
+
+    def test_closure_of_reduction(self):
+        reduction, _ = retworkx.transitive_reduction(self.dag)
+        closure, _ = retworkx.transitive_closure(self.dag)
+        reduction_closure, _ = retworkx.transitive_closure(reduction)
+        self.assertEqual(sorted(closure.edge_list()),
+                         sorted(reduction_closure.edge_list()))
+
+    def test_removed_nodes(self):
+        self.dag.remove_node(self.node_b)
+        res, node_map = retworkx.transitive_reduction(self.dag)
+        self.assertEqual(['a', 'c', 'd'], res.nodes())
+        self.assertEqual(
+            [(node_map[self.node_a], node_map[self.node_c]),
+             (node_map[self.node_c], node_map[self.node_d])],
+            sorted(res.edge_list()))
+
+    def test_cycle(self):
+        dag = retworkx.PyDAG(check_cycle=False)
+        node_a = dag.add_node('a')
+        node_b = dag.add_child(node_a, 'b', {})
+        dag.add_edge(node_b, node_a, {})
+        self.assertRaises(retworkx.DAGHasCycle,
+                          retworkx.transitive_reduction, dag)
+        self.assertRaises(retworkx.DAGHasCycle,
+                          retworkx.transitive_closure, dag)